@@ -0,0 +1,83 @@
+use std::collections::HashSet;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rust_algorithms::jump_game::{JumpGame, Scratch};
+
+/// The original traversal, kept here to compare against.
+fn hash_set_is_winnable(board: &[usize], starting_index: usize) -> bool {
+    let mut stack = Vec::<isize>::new();
+    let mut visited = HashSet::<isize>::new();
+
+    stack.push(starting_index as isize);
+
+    while let Some(current_index) = stack.pop() {
+        if visited.contains(&current_index) {
+            continue;
+        }
+        if current_index < 0 {
+            visited.insert(current_index);
+            continue;
+        }
+
+        match board.get(current_index as usize) {
+            Some(0) => return true,
+            Some(value) => {
+                stack.push(current_index - (*value as isize));
+                stack.push(current_index + (*value as isize));
+            }
+            None => {}
+        }
+
+        visited.insert(current_index);
+    }
+
+    false
+}
+
+/// Boards that make the traversal visit every (or every other) cell before finishing.
+fn board(name: &str, len: usize) -> Vec<usize> {
+    match name {
+        // a long chain of 1s ending in the only 0
+        "chain" => {
+            let mut board = vec![1; len];
+            board[len - 1] = 0;
+            board
+        }
+        // 2s everywhere, with the only 0 on an odd index that the even indices can never reach
+        "unreachable" => {
+            let mut board = vec![2; len];
+            board[1] = 0;
+            board
+        }
+        _ => unreachable!("unknown board {name}"),
+    }
+}
+
+fn is_winnable(c: &mut Criterion) {
+    for name in ["chain", "unreachable"] {
+        let mut group = c.benchmark_group(format!("is_winnable/{name}"));
+        group.sample_size(10);
+
+        for exponent in 3..=7 {
+            let len = 10usize.pow(exponent);
+            let board = board(name, len);
+            let game = JumpGame::new(board.clone(), 0);
+            let mut scratch = Scratch::new();
+
+            group.bench_with_input(BenchmarkId::new("hash_set", len), &board, |b, board| {
+                b.iter(|| hash_set_is_winnable(board, 0))
+            });
+            group.bench_with_input(BenchmarkId::new("bitset", len), &game, |b, game| {
+                b.iter(|| game.is_winnable())
+            });
+            group.bench_with_input(BenchmarkId::new("bitset_scratch", len), &game, |b, game| {
+                b.iter(|| game.is_winnable_with(&mut scratch))
+            });
+        }
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, is_winnable);
+criterion_main!(benches);
@@ -0,0 +1,318 @@
+//! Command line argument parsing.
+
+use std::fmt;
+
+use rust_algorithms::jump_game::Target;
+
+/// # Everything the command line asked for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Args {
+    pub command: Command,
+    /// Write JSON instead of text, one value per line.
+    pub json: bool,
+}
+
+/// # The subcommands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Solve(InputOptions),
+    Analyze(InputOptions),
+    Render(InputOptions),
+    Generate(GenerateOptions),
+    Play(InputOptions),
+    Help,
+}
+
+/// # Where to read puzzles from.
+///
+/// A board given with `--board` is used first, then any files. If neither is given, puzzles
+/// are read from stdin.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct InputOptions {
+    pub board: Option<String>,
+    pub start: Option<String>,
+    pub rule: Option<String>,
+    pub boundary: Option<String>,
+    pub goal: Option<String>,
+    pub files: Vec<String>,
+}
+
+/// # How to generate random puzzles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GenerateOptions {
+    pub len: usize,
+    pub count: usize,
+    pub seed: Option<u64>,
+    pub target: Target,
+    pub max_value: Option<usize>,
+}
+
+impl Default for GenerateOptions {
+    fn default() -> Self {
+        Self {
+            len: 10,
+            count: 1,
+            seed: None,
+            target: Target::Winnable,
+            max_value: None,
+        }
+    }
+}
+
+/// # Why the command line could not be understood.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UsageError {
+    MissingCommand,
+    UnknownCommand(String),
+    UnknownOption(String),
+    MissingValue(String),
+    InvalidValue {
+        option: String,
+        value: String,
+    },
+    UnexpectedArgument(String),
+    /// An option was given more than once.
+    DuplicateOption(String),
+    /// Options that only make sense alongside `--board`.
+    NeedsBoard(String),
+    /// A command that writes no JSON was given `--json`.
+    NoJson(String),
+    /// `play` reads moves from stdin, so its puzzles have to come from somewhere else.
+    PlayFromStdin,
+}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UsageError::MissingCommand => write!(f, "missing a command"),
+            UsageError::UnknownCommand(command) => write!(f, "unknown command `{command}`"),
+            UsageError::UnknownOption(option) => write!(f, "unknown option `{option}`"),
+            UsageError::MissingValue(option) => write!(f, "`{option}` needs a value"),
+            UsageError::InvalidValue { option, value } => {
+                write!(f, "`{value}` is not a valid value for `{option}`")
+            }
+            UsageError::UnexpectedArgument(argument) => {
+                write!(f, "unexpected argument `{argument}`")
+            }
+            UsageError::DuplicateOption(option) => {
+                write!(f, "`{option}` can only be given once")
+            }
+            UsageError::NeedsBoard(option) => {
+                write!(f, "`{option}` can only be used with `--board`")
+            }
+            UsageError::NoJson(command) => write!(f, "`{command}` does not support `--json`"),
+            UsageError::PlayFromStdin => {
+                write!(
+                    f,
+                    "`play` reads moves from stdin, give it `--board` or a puzzle file"
+                )
+            }
+        }
+    }
+}
+
+/// # Parses the arguments that follow the program name.
+pub fn parse(args: &[String]) -> Result<Args, UsageError> {
+    let mut args = args.iter();
+    let command = args.next().ok_or(UsageError::MissingCommand)?;
+    let rest: Vec<&String> = args.collect();
+    let json = match rest.iter().filter(|arg| **arg == "--json").count() {
+        0 => false,
+        1 => true,
+        _ => return Err(UsageError::DuplicateOption("--json".to_string())),
+    };
+    let rest: Vec<&str> = rest
+        .into_iter()
+        .filter(|arg| *arg != "--json")
+        .map(String::as_str)
+        .collect();
+
+    let command = match command.as_str() {
+        "solve" => Command::Solve(parse_input(&rest)?),
+        "analyze" => Command::Analyze(parse_input(&rest)?),
+        "render" => Command::Render(parse_input(&rest)?),
+        "generate" => Command::Generate(parse_generate(&rest)?),
+        "play" if json => return Err(UsageError::NoJson(command.to_string())),
+        "play" => {
+            let options = parse_input(&rest)?;
+            let no_input = options.board.is_none() && options.files.is_empty();
+            if no_input || options.files.iter().any(|file| file == "-") {
+                return Err(UsageError::PlayFromStdin);
+            }
+            Command::Play(options)
+        }
+        "help" | "--help" | "-h" => Command::Help,
+        other => return Err(UsageError::UnknownCommand(other.to_string())),
+    };
+    Ok(Args { command, json })
+}
+
+fn parse_input(args: &[&str]) -> Result<InputOptions, UsageError> {
+    let mut options = InputOptions::default();
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        let slot = match arg {
+            "--board" => &mut options.board,
+            "--start" => &mut options.start,
+            "--rule" => &mut options.rule,
+            "--boundary" => &mut options.boundary,
+            "--goal" => &mut options.goal,
+            "-" => {
+                options.files.push(arg.to_string());
+                continue;
+            }
+            option if option.starts_with('-') => {
+                return Err(UsageError::UnknownOption(option.to_string()));
+            }
+            file => {
+                options.files.push(file.to_string());
+                continue;
+            }
+        };
+        if slot.is_some() {
+            return Err(UsageError::DuplicateOption(arg.to_string()));
+        }
+        let value = args
+            .next()
+            .ok_or_else(|| UsageError::MissingValue(arg.to_string()))?;
+        if value.contains('\n') {
+            return Err(invalid(arg, value));
+        }
+        *slot = Some(value.to_string());
+    }
+
+    if options.board.is_none() {
+        let board_options = [
+            ("--start", &options.start),
+            ("--rule", &options.rule),
+            ("--boundary", &options.boundary),
+            ("--goal", &options.goal),
+        ];
+        if let Some((option, _)) = board_options.iter().find(|(_, value)| value.is_some()) {
+            return Err(UsageError::NeedsBoard(option.to_string()));
+        }
+    }
+    Ok(options)
+}
+
+fn parse_generate(args: &[&str]) -> Result<GenerateOptions, UsageError> {
+    let mut options = GenerateOptions::default();
+    let mut seen = Vec::new();
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        if !arg.starts_with('-') {
+            return Err(UsageError::UnexpectedArgument(arg.to_string()));
+        }
+        if seen.contains(&arg) {
+            return Err(UsageError::DuplicateOption(arg.to_string()));
+        }
+        seen.push(arg);
+        let mut value = || {
+            args.next()
+                .copied()
+                .ok_or_else(|| UsageError::MissingValue(arg.to_string()))
+        };
+        match arg {
+            "--len" => options.len = parse_number(arg, value()?)?,
+            "--count" => options.count = parse_number(arg, value()?)?,
+            "--seed" => options.seed = Some(parse_number(arg, value()?)?),
+            "--max-value" => options.max_value = Some(parse_number(arg, value()?)?),
+            "--target" => {
+                let value = value()?;
+                options.target = match value {
+                    "winnable" => Target::Winnable,
+                    "unwinnable" => Target::Unwinnable,
+                    jumps => Target::MinJumps(parse_number(arg, jumps)?),
+                };
+            }
+            option => return Err(UsageError::UnknownOption(option.to_string())),
+        }
+    }
+    Ok(options)
+}
+
+fn parse_number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, UsageError> {
+    value.parse().map_err(|_| invalid(option, value))
+}
+
+fn invalid(option: &str, value: &str) -> UsageError {
+    UsageError::InvalidValue {
+        option: option.to_string(),
+        value: value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn parse_str(args: &str) -> Result<Args, UsageError> {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        parse(&args)
+    }
+
+    #[test]
+    fn parses_board_options() {
+        let args = parse_str("solve --board 1,2,0 --start 1 --rule forward --json").unwrap();
+        assert!(args.json);
+        assert_eq!(
+            args.command,
+            Command::Solve(InputOptions {
+                board: Some("1,2,0".to_string()),
+                start: Some("1".to_string()),
+                rule: Some("forward".to_string()),
+                ..InputOptions::default()
+            })
+        );
+    }
+
+    #[test]
+    fn parses_files() {
+        let args = parse_str("render a.txt - b.txt").unwrap();
+        assert!(!args.json);
+        assert_eq!(
+            args.command,
+            Command::Render(InputOptions {
+                files: vec!["a.txt".to_string(), "-".to_string(), "b.txt".to_string()],
+                ..InputOptions::default()
+            })
+        );
+    }
+
+    #[test_case("generate", GenerateOptions::default())]
+    #[test_case("generate --len 5 --count 3 --seed 9 --target unwinnable --max-value 2", GenerateOptions {
+        len: 5,
+        count: 3,
+        seed: Some(9),
+        target: Target::Unwinnable,
+        max_value: Some(2),
+    })]
+    #[test_case("generate --target 4", GenerateOptions { target: Target::MinJumps(4), ..GenerateOptions::default() })]
+    fn parses_generate_options(args: &str, expected: GenerateOptions) {
+        assert_eq!(
+            parse_str(args).unwrap().command,
+            Command::Generate(expected)
+        );
+    }
+
+    #[test_case("", UsageError::MissingCommand)]
+    #[test_case("jump", UsageError::UnknownCommand("jump".to_string()))]
+    #[test_case("solve --verbose", UsageError::UnknownOption("--verbose".to_string()))]
+    #[test_case("solve --board", UsageError::MissingValue("--board".to_string()))]
+    #[test_case("solve --start 2", UsageError::NeedsBoard("--start".to_string()))]
+    #[test_case("generate --len many", UsageError::InvalidValue { option: "--len".to_string(), value: "many".to_string() })]
+    #[test_case("generate --target sometimes", UsageError::InvalidValue { option: "--target".to_string(), value: "sometimes".to_string() })]
+    #[test_case("play", UsageError::PlayFromStdin)]
+    #[test_case("play a.txt -", UsageError::PlayFromStdin)]
+    #[test_case("play --board 1,0 --json", UsageError::NoJson("play".to_string()))]
+    #[test_case("generate file.txt", UsageError::UnexpectedArgument("file.txt".to_string()))]
+    #[test_case("solve --board 1,0 --start 0 --start 1", UsageError::DuplicateOption("--start".to_string()))]
+    #[test_case("render --board 1,0 --board 2,0", UsageError::DuplicateOption("--board".to_string()))]
+    #[test_case("solve --board 1,0 --json --json", UsageError::DuplicateOption("--json".to_string()))]
+    #[test_case("generate --seed 1 --len 4 --seed 2", UsageError::DuplicateOption("--seed".to_string()))]
+    #[test_case("generate --target 3 --target winnable", UsageError::DuplicateOption("--target".to_string()))]
+    fn rejects_bad_arguments(args: &str, expected: UsageError) {
+        assert_eq!(parse_str(args).unwrap_err(), expected);
+    }
+}
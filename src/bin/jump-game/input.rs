@@ -0,0 +1,214 @@
+//! Reading puzzles from arguments, files and stdin.
+
+use std::fs;
+use std::io::Read;
+
+use rust_algorithms::jump_game::{parse_puzzles, ParseError, Puzzle};
+use serde_json::Value;
+
+use crate::args::InputOptions;
+use crate::CliError;
+
+/// # Reads every puzzle the options point at, in order.
+///
+/// Files (and stdin) can hold either the puzzle text format or JSON. JSON input is any number of
+/// games or puzzles, optionally wrapped in arrays.
+pub fn read_puzzles(options: &InputOptions, stdin: &mut dyn Read) -> Result<Vec<Puzzle>, CliError> {
+    let mut puzzles = Vec::new();
+    if let Some(board) = &options.board {
+        puzzles.push(board_puzzle(board, options)?);
+    }
+
+    let mut sources: Vec<&str> = options.files.iter().map(String::as_str).collect();
+    if puzzles.is_empty() && sources.is_empty() {
+        sources.push("-");
+    }
+    for source in sources {
+        let (name, text) = if source == "-" {
+            let mut text = String::new();
+            stdin
+                .read_to_string(&mut text)
+                .map_err(|error| CliError::Read {
+                    source: "stdin".to_string(),
+                    error,
+                })?;
+            ("stdin", text)
+        } else {
+            let text = fs::read_to_string(source).map_err(|error| CliError::Read {
+                source: source.to_string(),
+                error,
+            })?;
+            (source, text)
+        };
+
+        let parsed = parse_source(name, &text)?;
+        if parsed.is_empty() {
+            return Err(CliError::NoPuzzles(name.to_string()));
+        }
+        puzzles.extend(parsed);
+    }
+    Ok(puzzles)
+}
+
+/// Builds a puzzle from `--board` and friends by writing them out in the puzzle text format, so
+/// they are checked exactly like a puzzle file would be.
+fn board_puzzle(board: &str, options: &InputOptions) -> Result<Puzzle, CliError> {
+    let start = options.start.as_deref().unwrap_or("0");
+    let mut text = format!("board: {}\nstart: {start}", board.replace(',', " "));
+    let keys = [
+        ("rule", &options.rule),
+        ("boundary", &options.boundary),
+        ("goal", &options.goal),
+    ];
+    for (key, value) in keys {
+        if let Some(value) = value {
+            text.push_str(&format!("\n{key}: {value}"));
+        }
+    }
+    text.parse()
+        .map_err(|error: ParseError| CliError::Arguments(error.kind))
+}
+
+fn parse_source(name: &str, text: &str) -> Result<Vec<Puzzle>, CliError> {
+    if text.trim_start().starts_with(['{', '[']) {
+        parse_json(text).map_err(|error| CliError::Json {
+            source: name.to_string(),
+            error,
+        })
+    } else {
+        parse_puzzles(text).map_err(|error| CliError::Parse {
+            source: name.to_string(),
+            error,
+        })
+    }
+}
+
+fn parse_json(text: &str) -> Result<Vec<Puzzle>, serde_json::Error> {
+    let mut puzzles = Vec::new();
+    for value in serde_json::Deserializer::from_str(text).into_iter::<Value>() {
+        push_json(value?, &mut puzzles)?;
+    }
+    Ok(puzzles)
+}
+
+/// Games are told apart from puzzles by puzzles having a `game` field.
+fn push_json(value: Value, puzzles: &mut Vec<Puzzle>) -> Result<(), serde_json::Error> {
+    match value {
+        Value::Array(values) => values
+            .into_iter()
+            .try_for_each(|value| push_json(value, puzzles)),
+        value if value.get("game").is_some() => {
+            puzzles.push(serde_json::from_value(value)?);
+            Ok(())
+        }
+        value => {
+            puzzles.push(Puzzle {
+                name: None,
+                expected: None,
+                game: serde_json::from_value(value)?,
+            });
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_algorithms::jump_game::{
+        Boundary, Expectation, JumpGameError, ParseErrorKind, RuleKind,
+    };
+    use test_case::test_case;
+
+    fn board_options(board: &str) -> InputOptions {
+        InputOptions {
+            board: Some(board.to_string()),
+            ..InputOptions::default()
+        }
+    }
+
+    #[test]
+    fn boards_come_from_arguments() {
+        let options = InputOptions {
+            start: Some("3".to_string()),
+            rule: Some("forward".to_string()),
+            boundary: Some("wrap".to_string()),
+            goal: Some("index 0".to_string()),
+            ..board_options("2,3 1,1 4")
+        };
+        let puzzles = read_puzzles(&options, &mut "unused".as_bytes()).unwrap();
+        assert_eq!(puzzles.len(), 1);
+        let game = &puzzles[0].game;
+        assert_eq!(game.board(), [2, 3, 1, 1, 4]);
+        assert_eq!(game.starting_index(), 3);
+        assert_eq!(*game.rule(), RuleKind::Forward);
+        assert_eq!(game.boundary(), Boundary::Wrap);
+    }
+
+    #[test_case("1,2", ParseErrorKind::InvalidGame(JumpGameError::MissingZero { board_len: 2 }))]
+    #[test_case("1,x,0", ParseErrorKind::InvalidNumber("x".to_string()))]
+    fn bad_boards_are_argument_errors(board: &str, expected: ParseErrorKind) {
+        let error = read_puzzles(&board_options(board), &mut "".as_bytes()).unwrap_err();
+        assert!(matches!(error, CliError::Arguments(kind) if kind == expected));
+    }
+
+    #[test]
+    fn puzzle_text_comes_from_stdin() {
+        let mut stdin =
+            "name: a\nboard: 1 0\nstart: 0\nexpect: 1\n---\nboard: 2 0\nstart: 0".as_bytes();
+        let puzzles = read_puzzles(&InputOptions::default(), &mut stdin).unwrap();
+        assert_eq!(puzzles.len(), 2);
+        assert_eq!(puzzles[0].name.as_deref(), Some("a"));
+        assert_eq!(puzzles[0].expected, Some(Expectation::MinJumps(1)));
+    }
+
+    #[test]
+    fn json_comes_from_stdin() {
+        let mut stdin = r#"
+            {"board":[1,0],"starting_index":0}
+            [{"name":"b","expected":"unwinnable","game":{"board":[2,0],"starting_index":0}}]
+        "#
+        .as_bytes();
+        let puzzles = read_puzzles(&InputOptions::default(), &mut stdin).unwrap();
+        assert_eq!(puzzles.len(), 2);
+        assert!(puzzles[0].game.is_winnable());
+        assert_eq!(puzzles[1].name.as_deref(), Some("b"));
+        assert_eq!(puzzles[1].meets_expectation(), Some(true));
+    }
+
+    #[test_case("", "no puzzles in stdin")]
+    #[test_case(
+        "board: 1 2\nstart: 0",
+        "stdin: line 1, column 8: Board must contain at least one 0"
+    )]
+    #[test_case(
+        r#"{"board":[],"starting_index":0}"#,
+        "stdin: Board must have at least one element"
+    )]
+    fn bad_input_is_reported_with_its_source(stdin: &str, message: &str) {
+        let error = read_puzzles(&InputOptions::default(), &mut stdin.as_bytes()).unwrap_err();
+        assert!(error.to_string().starts_with(message), "{error}");
+    }
+
+    #[test]
+    fn fixture_files_are_read() {
+        let options = InputOptions {
+            files: vec!["fixtures/jump_game.txt".to_string()],
+            ..InputOptions::default()
+        };
+        let puzzles = read_puzzles(&options, &mut "".as_bytes()).unwrap();
+        assert!(puzzles.len() > 20);
+    }
+
+    #[test]
+    fn missing_files_are_reported() {
+        let options = InputOptions {
+            files: vec!["no/such/file.txt".to_string()],
+            ..InputOptions::default()
+        };
+        let error = read_puzzles(&options, &mut "".as_bytes()).unwrap_err();
+        assert!(error
+            .to_string()
+            .starts_with("could not read no/such/file.txt"));
+    }
+}
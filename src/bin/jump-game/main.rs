@@ -0,0 +1,452 @@
+//! # A command line tool for solving and inspecting jump games.
+//!
+//! Run `jump-game help` for usage. Needs the `cli` feature:
+//!
+//! ```text
+//! cargo run --features cli --bin jump-game -- solve --board 1,2,3,0,3,2 --start 4
+//! ```
+
+mod args;
+mod input;
+mod play;
+mod render;
+
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufReader, Read, Write};
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rust_algorithms::jump_game::{
+    write_puzzles, Expectation, JumpGameGenerator, ParseError, ParseErrorKind, Puzzle, RuleKind,
+    Target,
+};
+use serde_json::json;
+
+use args::{Args, Command, GenerateOptions, UsageError};
+
+const USAGE: &str = "\
+Solve and inspect jump games.
+
+Usage: jump-game <command> [options] [files...]
+
+Commands:
+  solve       print whether each puzzle is winnable, a shortest path and its number of jumps,
+              or why it can't be won
+  analyze     print the winnability and distance to a goal of every index
+  render      draw each board and the jumps of its shortest path
+  generate    write random puzzles
+  play        play puzzles interactively, reading moves from stdin
+  help        print this message
+
+Input (solve, analyze, render, play):
+  --board <values>     a board, with values separated by commas or spaces
+  --start <index>      the starting index for --board, defaults to 0
+  --rule <rule>        symmetric (default), forward or bidirectional
+  --boundary <kind>    wall (default) or wrap
+  --goal <goal>        zero (default), exit-right, \"index <indices...>\" or \"value <value>\"
+  [files...]           puzzle files in the text format or JSON, `-` for stdin
+  Puzzles are read from stdin when there is no --board and no files, except by play.
+
+Generate:
+  --len <n>            board length, defaults to 10
+  --count <n>          number of puzzles, defaults to 1
+  --seed <n>           seed for reproducible puzzles, defaults to the current time
+  --target <target>    winnable (default), unwinnable, or the fewest jumps a win should take
+  --max-value <n>      the largest jump length on the board
+
+Output:
+  --json               write one JSON value per puzzle per line
+
+solve exits with status 1 if any puzzle does not meet its `expect`ed answer, and play exits with
+status 1 unless every puzzle is won.";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = run(&args, &mut io::stdin().lock(), &mut io::stdout().lock());
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(error) => {
+            eprintln!("jump-game: {error}");
+            if matches!(error, CliError::Usage(_)) {
+                eprintln!("run `jump-game help` for usage");
+            }
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// # Everything that can stop a command from running.
+#[derive(Debug)]
+pub enum CliError {
+    Usage(UsageError),
+    /// The board given as arguments is not a valid game.
+    Arguments(ParseErrorKind),
+    Read {
+        source: String,
+        error: io::Error,
+    },
+    Parse {
+        source: String,
+        error: ParseError,
+    },
+    Json {
+        source: String,
+        error: serde_json::Error,
+    },
+    NoPuzzles(String),
+    /// Only the symmetric rule can be played interactively.
+    UnsupportedRule(RuleKind),
+    Generate {
+        len: usize,
+        target: Target,
+    },
+    Write(io::Error),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::Usage(error) => write!(f, "{error}"),
+            CliError::Arguments(kind) => write!(f, "invalid board: {kind}"),
+            CliError::Read { source, error } => write!(f, "could not read {source}: {error}"),
+            CliError::Parse { source, error } => write!(f, "{source}: {error}"),
+            CliError::Json { source, error } => write!(f, "{source}: {error}"),
+            CliError::NoPuzzles(source) => write!(f, "no puzzles in {source}"),
+            CliError::UnsupportedRule(rule) => {
+                write!(f, "only the symmetric rule can be played, not {rule}")
+            }
+            CliError::Generate { len, target } => {
+                write!(
+                    f,
+                    "could not generate a board of length {len} for {target:?}"
+                )
+            }
+            CliError::Write(error) => write!(f, "could not write output: {error}"),
+        }
+    }
+}
+
+impl Error for CliError {}
+
+impl From<io::Error> for CliError {
+    fn from(error: io::Error) -> Self {
+        CliError::Write(error)
+    }
+}
+
+impl From<UsageError> for CliError {
+    fn from(error: UsageError) -> Self {
+        CliError::Usage(error)
+    }
+}
+
+/// # Runs a command, returning whether it succeeded.
+fn run(args: &[String], stdin: &mut dyn Read, out: &mut dyn Write) -> Result<bool, CliError> {
+    let Args { command, json } = args::parse(args)?;
+    match command {
+        Command::Solve(options) => solve(&input::read_puzzles(&options, stdin)?, json, out),
+        Command::Analyze(options) => {
+            analyze(&input::read_puzzles(&options, stdin)?, json, out)?;
+            Ok(true)
+        }
+        Command::Render(options) => {
+            render(&input::read_puzzles(&options, stdin)?, json, out)?;
+            Ok(true)
+        }
+        Command::Generate(options) => {
+            generate(&options, json, out)?;
+            Ok(true)
+        }
+        Command::Play(options) => {
+            let puzzles = input::read_puzzles(&options, stdin)?;
+            play::play(&puzzles, &mut BufReader::new(stdin), out)
+        }
+        Command::Help => {
+            writeln!(out, "{USAGE}")?;
+            Ok(true)
+        }
+    }
+}
+
+fn title(puzzle: &Puzzle, index: usize) -> String {
+    puzzle
+        .name
+        .clone()
+        .unwrap_or_else(|| format!("puzzle {}", index + 1))
+}
+
+fn solve(puzzles: &[Puzzle], json: bool, out: &mut dyn Write) -> Result<bool, CliError> {
+    let mut all_met = true;
+    for (index, puzzle) in puzzles.iter().enumerate() {
+        let path = puzzle.game.shortest_path();
+        let min_jumps = puzzle.game.min_jumps();
+        let meets_expectation = puzzle.meets_expectation();
+        all_met &= meets_expectation != Some(false);
+
+        if json {
+            let value = json!({
+                "name": puzzle.name,
+                "winnable": path.is_some(),
+                "min_jumps": min_jumps,
+                "path": path,
+                "expected": puzzle.expected,
+                "meets_expectation": meets_expectation,
+            });
+            writeln!(out, "{value}")?;
+            continue;
+        }
+
+        if index > 0 {
+            writeln!(out)?;
+        }
+        let title = title(puzzle, index);
+        match (&path, min_jumps) {
+            (Some(path), Some(min_jumps)) => {
+                let plural = if min_jumps == 1 { "" } else { "s" };
+                writeln!(out, "{title}: winnable in {min_jumps} jump{plural}")?;
+                let mut steps: Vec<String> = path.iter().map(usize::to_string).collect();
+                if min_jumps == path.len() {
+                    steps.push("exit".to_string());
+                }
+                writeln!(out, "  path: {}", steps.join(" -> "))?;
+            }
+            _ => {
+                writeln!(out, "{title}: unwinnable")?;
+                writeln!(out, "  why: {}", puzzle.game.diagnose())?;
+            }
+        }
+        if let (Some(expected), Some(met)) = (puzzle.expected, meets_expectation) {
+            let verdict = if met { "ok" } else { "FAILED" };
+            writeln!(out, "  expected {expected}: {verdict}")?;
+        }
+    }
+    Ok(all_met)
+}
+
+fn analyze(puzzles: &[Puzzle], json: bool, out: &mut dyn Write) -> Result<(), CliError> {
+    for (index, puzzle) in puzzles.iter().enumerate() {
+        let game = &puzzle.game;
+        let analysis = game.analyze();
+
+        if json {
+            let cells: Vec<_> = game
+                .board()
+                .iter()
+                .zip(analysis.distances())
+                .enumerate()
+                .map(|(index, (value, distance))| {
+                    json!({
+                        "index": index,
+                        "value": value,
+                        "winnable": distance.is_some(),
+                        "distance": distance,
+                    })
+                })
+                .collect();
+            let value = json!({
+                "name": puzzle.name,
+                "starting_index": game.starting_index(),
+                "cells": cells,
+            });
+            writeln!(out, "{value}")?;
+            continue;
+        }
+
+        if index > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{}", title(puzzle, index))?;
+        writeln!(out, "   index  value  winnable  distance")?;
+        for (cell, (value, distance)) in game.board().iter().zip(analysis.distances()).enumerate() {
+            let start = if cell == game.starting_index() {
+                '>'
+            } else {
+                ' '
+            };
+            let winnable = if distance.is_some() { "yes" } else { "no" };
+            let distance = distance.map_or("-".to_string(), |distance| distance.to_string());
+            writeln!(
+                out,
+                " {start} {cell:>5}  {value:>5}  {winnable:>8}  {distance:>8}"
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn render(puzzles: &[Puzzle], json: bool, out: &mut dyn Write) -> Result<(), CliError> {
+    for (index, puzzle) in puzzles.iter().enumerate() {
+        let diagram = render::diagram(&puzzle.game);
+        if json {
+            let value = json!({ "name": puzzle.name, "diagram": diagram });
+            writeln!(out, "{value}")?;
+            continue;
+        }
+
+        if index > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{}\n{diagram}", title(puzzle, index))?;
+    }
+    Ok(())
+}
+
+fn generate(options: &GenerateOptions, json: bool, out: &mut dyn Write) -> Result<(), CliError> {
+    let seed = options.seed.unwrap_or_else(|| {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+    });
+    let mut generator = JumpGameGenerator::new(seed);
+    if let Some(max_value) = options.max_value {
+        generator = generator.with_max_value(max_value);
+    }
+
+    let mut puzzles = Vec::new();
+    for number in 1..=options.count {
+        let game = generator
+            .generate(options.len, options.target)
+            .ok_or(CliError::Generate {
+                len: options.len,
+                target: options.target,
+            })?;
+        let expected = match game.min_jumps() {
+            Some(jumps) => Expectation::MinJumps(jumps),
+            None => Expectation::Unwinnable,
+        };
+        puzzles.push(Puzzle {
+            name: Some(format!("seed {seed} #{number}")),
+            expected: Some(expected),
+            game: game.with_rule(RuleKind::Symmetric),
+        });
+    }
+
+    if json {
+        for puzzle in &puzzles {
+            let value = serde_json::to_string(puzzle).map_err(io::Error::from)?;
+            writeln!(out, "{value}")?;
+        }
+    } else {
+        writeln!(out, "{}", write_puzzles(&puzzles))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn run_str(args: &str, stdin: &str) -> (Result<bool, CliError>, String) {
+        let args: Vec<String> = args.split_whitespace().map(String::from).collect();
+        let mut out = Vec::new();
+        let result = run(&args, &mut stdin.as_bytes(), &mut out);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test_case(
+        "solve --board 1,2,3,0,3,2 --start 4",
+        "puzzle 1: winnable in 2 jumps\n  path: 4 -> 1 -> 3\n"
+    )]
+    #[test_case("solve --board 2,0 --start 0", "puzzle 1: unwinnable\n  why: from index 0 you can reach {0}; all moves from these either exit the board or return to the set; zeros at {1} are unreachable\n")]
+    #[test_case(
+        "solve --board 1,2 --goal exit-right",
+        "puzzle 1: winnable in 2 jumps\n  path: 0 -> 1 -> exit\n"
+    )]
+    #[test_case("solve --board 1,1,0 --json", "{\"expected\":null,\"meets_expectation\":null,\"min_jumps\":2,\"name\":null,\"path\":[0,1,2],\"winnable\":true}\n")]
+    fn solves_boards(args: &str, expected: &str) {
+        let (result, out) = run_str(args, "");
+        assert!(result.unwrap());
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn solve_checks_expectations() {
+        let stdin = "name: right\nboard: 1 0\nstart: 0\nexpect: 1\n---\nname: wrong\nboard: 2 0\nstart: 0\nexpect: winnable";
+        let (result, out) = run_str("solve", stdin);
+        assert!(!result.unwrap());
+        assert_eq!(
+            out,
+            "right: winnable in 1 jump\n  path: 0 -> 1\n  expected 1: ok\n\nwrong: unwinnable\n  why: from index 0 you can reach {0}; all moves from these either exit the board or return to the set; zeros at {1} are unreachable\n  expected winnable: FAILED\n"
+        );
+    }
+
+    #[test]
+    fn solves_the_fixture_file() {
+        let (result, _) = run_str("solve fixtures/jump_game.txt", "");
+        assert!(result.unwrap());
+    }
+
+    #[test]
+    fn analyzes_boards() {
+        let (result, out) = run_str("analyze --board 1,2,0 --start 1", "");
+        assert!(result.unwrap());
+        let expected = [
+            "puzzle 1",
+            "   index  value  winnable  distance",
+            "       0      1        no         -",
+            " >     1      2        no         -",
+            "       2      0       yes         0",
+            "",
+        ];
+        assert_eq!(out, expected.join("\n"));
+
+        let (_, out) = run_str("analyze --board 1,2,0 --json", "");
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert_eq!(value["cells"][1]["winnable"], false);
+        assert_eq!(value["cells"][0]["distance"], serde_json::Value::Null);
+        assert_eq!(value["cells"][2]["distance"], 0);
+    }
+
+    #[test]
+    fn renders_boards() {
+        let (result, out) = run_str("render --board 2,0 --json", "");
+        assert!(result.unwrap());
+        let value: serde_json::Value = serde_json::from_str(&out).unwrap();
+        assert!(value["diagram"]
+            .as_str()
+            .unwrap()
+            .ends_with("no winning path"));
+    }
+
+    #[test_case("--target winnable")]
+    #[test_case("--target unwinnable")]
+    #[test_case("--target 3")]
+    fn generated_puzzles_meet_their_expectations(target: &str) {
+        let (result, generated) = run_str(
+            &format!("generate --len 12 --count 5 --seed 3 {target}"),
+            "",
+        );
+        assert!(result.unwrap());
+        let (result, out) = run_str("solve", &generated);
+        assert!(result.unwrap(), "{out}");
+
+        let (_, json) = run_str(
+            &format!("generate --len 12 --count 5 --seed 3 {target} --json"),
+            "",
+        );
+        assert_eq!(json.lines().count(), 5);
+        let (result, from_json) = run_str("solve", &json);
+        assert!(result.unwrap());
+        assert_eq!(from_json, out);
+    }
+
+    #[test]
+    fn generate_reports_impossible_targets() {
+        let (result, _) = run_str("generate --len 1 --target unwinnable", "");
+        assert_eq!(
+            result.unwrap_err().to_string(),
+            "could not generate a board of length 1 for Unwinnable"
+        );
+    }
+
+    #[test]
+    fn help_prints_usage() {
+        let (result, out) = run_str("help", "");
+        assert!(result.unwrap());
+        assert!(out.starts_with("Solve and inspect jump games."));
+    }
+}
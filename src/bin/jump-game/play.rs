@@ -0,0 +1,159 @@
+//! Playing puzzles interactively, one jump per line of input.
+
+use std::io::{BufRead, Write};
+
+use rust_algorithms::jump_game::{
+    Direction, JumpGameSession, MoveError, Puzzle, SessionStatus, SymmetricExact,
+};
+
+use crate::{render, title, CliError};
+
+const COMMANDS: &str = "Jump (l)eft or (r)ight, (u)ndo, re(d)o, get a (h)int, or (q)uit.";
+
+/// # Plays each puzzle in turn, returning whether every one was won.
+///
+/// Stops at the end of the input or when the player quits.
+pub fn play(
+    puzzles: &[Puzzle],
+    input: &mut dyn BufRead,
+    out: &mut dyn Write,
+) -> Result<bool, CliError> {
+    for (index, puzzle) in puzzles.iter().enumerate() {
+        let rule =
+            SymmetricExact::try_from(*puzzle.game.rule()).map_err(CliError::UnsupportedRule)?;
+        let mut session = JumpGameSession::new(puzzle.game.clone().with_rule(rule));
+        if index > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{}", title(puzzle, index))?;
+
+        let mut redraw = true;
+        loop {
+            let position = session.position();
+            if redraw {
+                writeln!(out, "\n{}", render::board(session.game(), position, '^'))?;
+            }
+            redraw = false;
+            let value = session.game().board()[position];
+            match session.status() {
+                SessionStatus::Won => {
+                    let jumps = session.jumps();
+                    let plural = if jumps == 1 { "" } else { "s" };
+                    writeln!(out, "You won in {jumps} jump{plural}!")?;
+                    break;
+                }
+                SessionStatus::Stuck => writeln!(
+                    out,
+                    "You are on {position}, which jumps {value}, but there is no way to win from here. {COMMANDS}"
+                )?,
+                SessionStatus::InProgress => writeln!(
+                    out,
+                    "You are on {position}, which jumps {value}. {COMMANDS}"
+                )?,
+            }
+
+            let Some(command) = next_command(input, out)? else {
+                return Ok(false);
+            };
+            if let Ok(direction) = command.parse::<Direction>() {
+                match session.jump(direction) {
+                    Ok(_) => redraw = true,
+                    Err(MoveError::OutOfBounds { .. }) => {
+                        writeln!(out, "That jump would fall off the board!")?
+                    }
+                    Err(error) => writeln!(out, "{error}.")?,
+                }
+                continue;
+            }
+            match command.as_str() {
+                "u" | "undo" => {
+                    redraw = session.undo();
+                    if !redraw {
+                        writeln!(out, "There is nothing to undo.")?;
+                    }
+                }
+                "d" | "redo" => {
+                    redraw = session.redo().is_some();
+                    if !redraw {
+                        writeln!(out, "There is nothing to redo.")?;
+                    }
+                }
+                "h" | "hint" => match session.hint() {
+                    Some(direction) => writeln!(out, "Try jumping {direction}.")?,
+                    None => writeln!(out, "There is no way to win from here.")?,
+                },
+                "q" | "quit" => return Ok(false),
+                other => writeln!(out, "Unknown command `{other}`. {COMMANDS}")?,
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Prompts for and reads the next non-empty line, or `None` at the end of the input.
+fn next_command(input: &mut dyn BufRead, out: &mut dyn Write) -> Result<Option<String>, CliError> {
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(None);
+        }
+        let command = line.trim().to_lowercase();
+        if !command.is_empty() {
+            return Ok(Some(command));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play_str(puzzles: &str, input: &str) -> (Result<bool, CliError>, String) {
+        let puzzles = rust_algorithms::jump_game::parse_puzzles(puzzles).unwrap();
+        let mut out = Vec::new();
+        let result = play(&puzzles, &mut input.as_bytes(), &mut out);
+        (result, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn plays_to_a_win() {
+        let (result, out) = play_str("board: 1 2 3 0 3 2\nstart: 0", "h\nr\n\nleft\nu\nd\nr\n");
+        assert!(result.unwrap());
+        assert!(out.contains("Try jumping right."));
+        assert!(out.contains("That jump would fall off the board!"));
+        assert!(out.ends_with("          ^\nYou won in 2 jumps!\n"), "{out}");
+    }
+
+    #[test]
+    fn plays_every_puzzle() {
+        let (result, out) = play_str(
+            "name: a\nboard: 1 0\nstart: 0\n---\nname: b\nboard: 0 1\nstart: 1",
+            "r\nl\n",
+        );
+        assert!(result.unwrap());
+        assert_eq!(out.matches("You won in 1 jump!").count(), 2);
+    }
+
+    #[test]
+    fn quitting_and_running_out_of_input_are_losses() {
+        let (result, out) = play_str("board: 2 0\nstart: 0", "h\nu\nredo\nq\n");
+        assert!(!result.unwrap());
+        assert!(out.contains("which jumps 2, but there is no way to win from here."));
+        assert!(out.contains("There is no way to win from here."));
+        assert!(out.contains("There is nothing to undo."));
+        assert!(out.contains("There is nothing to redo."));
+
+        let (result, out) = play_str("board: 1 0\nstart: 0", "jump\n");
+        assert!(!result.unwrap());
+        assert!(out.contains("Unknown command `jump`."));
+    }
+
+    #[test]
+    fn only_the_symmetric_rule_can_be_played() {
+        let (result, _) = play_str("board: 1 0\nstart: 0\nrule: forward", "");
+        assert!(matches!(result, Err(CliError::UnsupportedRule(_))));
+    }
+}
@@ -0,0 +1,192 @@
+//! ASCII diagrams of a board and the jumps of its shortest winning path.
+
+use rust_algorithms::jump_game::{JumpGame, JumpRule};
+
+/// # Draws the board with its indices, marks the start (`S`) and goal cells (`G`), then draws
+/// each jump of the shortest winning path on its own line.
+///
+/// ```text
+///  0  1  2  3  4  5
+/// [1][2][3][0][3][2]
+///  S        G
+///  +-->                 0 -> 1
+///     +----->           1 -> 3
+/// ```
+pub fn diagram<R: JumpRule>(game: &JumpGame<R>) -> String {
+    let layout = Layout::new(game.board());
+    let column = |index| layout.column(index);
+    let width = layout.width();
+    let mut lines = layout.board(game, game.starting_index(), 'S');
+
+    match game.shortest_path() {
+        None => lines.push("no winning path".to_string()),
+        Some(path) => {
+            for jump in path.windows(2) {
+                let (from, to) = (jump[0], jump[1]);
+                lines.push(arrow(
+                    column(from),
+                    column(to),
+                    width,
+                    format!("{from} -> {to}"),
+                ));
+            }
+            let exits = game.min_jumps() == Some(path.len());
+            if exits {
+                let from = path[path.len() - 1];
+                lines.push(arrow(
+                    column(from),
+                    width + 1,
+                    width,
+                    format!("{from} -> exit"),
+                ));
+            }
+        }
+    }
+
+    join(lines)
+}
+
+/// # Draws the board with its indices, marks the goal cells (`G`), and puts `marker` under
+/// `position`.
+pub fn board<R: JumpRule>(game: &JumpGame<R>, position: usize, marker: char) -> String {
+    join(Layout::new(game.board()).board(game, position, marker))
+}
+
+fn join(lines: Vec<String>) -> String {
+    lines
+        .iter()
+        .map(|line| line.trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// How wide the cells of a board are drawn.
+struct Layout {
+    len: usize,
+    value_width: usize,
+}
+
+impl Layout {
+    fn new(board: &[usize]) -> Self {
+        let digits = |value: usize| value.to_string().len();
+        let value_width = board
+            .iter()
+            .map(|&value| digits(value))
+            .chain([digits(board.len() - 1)])
+            .max()
+            .unwrap_or(1);
+        Self {
+            len: board.len(),
+            value_width,
+        }
+    }
+
+    fn cell_width(&self) -> usize {
+        self.value_width + 2
+    }
+
+    fn width(&self) -> usize {
+        self.len * self.cell_width()
+    }
+
+    /// The column of the last digit of a cell, which the markers and arrows line up with.
+    fn column(&self, index: usize) -> usize {
+        index * self.cell_width() + self.cell_width() - 2
+    }
+
+    fn board<R: JumpRule>(&self, game: &JumpGame<R>, position: usize, marker: char) -> Vec<String> {
+        let value_width = self.value_width;
+        let indices = (0..self.len)
+            .map(|index| format!("{index:>w$} ", w = value_width + 1))
+            .collect();
+        let cells = game
+            .board()
+            .iter()
+            .map(|value| format!("[{value:>value_width$}]"))
+            .collect();
+
+        let mut markers = vec![' '; self.width()];
+        for (index, distance) in game.analyze().distances().iter().enumerate() {
+            if *distance == Some(0) {
+                markers[self.column(index)] = 'G';
+            }
+        }
+        markers[self.column(position)] = marker;
+
+        vec![indices, cells, markers.into_iter().collect()]
+    }
+}
+
+/// Draws a jump from one column to another, with a label past the end of the board.
+fn arrow(from: usize, to: usize, width: usize, label: String) -> String {
+    let mut line = vec![' '; width.max(to) + 1];
+    let (left, right) = (from.min(to), from.max(to));
+    line[left..=right].fill('-');
+    line[from] = '+';
+    line[to] = if to > from { '>' } else { '<' };
+    let line: String = line.into_iter().collect();
+    format!("{line:<w$}  {label}", w = width + 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_algorithms::jump_game::RuleKind;
+
+    fn game(text: &str) -> JumpGame<RuleKind> {
+        let puzzle: rust_algorithms::jump_game::Puzzle = text.parse().unwrap();
+        puzzle.game
+    }
+
+    #[test]
+    fn draws_the_shortest_path() {
+        let diagram = diagram(&game("board: 1 2 3 0 3 2\nstart: 0"));
+        let expected = [
+            " 0  1  2  3  4  5",
+            "[1][2][3][0][3][2]",
+            " S        G",
+            " +-->                 0 -> 1",
+            "    +----->           1 -> 3",
+        ];
+        assert_eq!(diagram, expected.join("\n"));
+    }
+
+    #[test]
+    fn draws_backwards_jumps_and_wide_values() {
+        let diagram = diagram(&game("board: 0 12 2 1\nstart: 3"));
+        let expected = [
+            "  0   1   2   3",
+            "[ 0][12][ 2][ 1]",
+            "  G           S",
+            "          <---+     3 -> 2",
+            "  <-------+         2 -> 0",
+        ];
+        assert_eq!(diagram, expected.join("\n"));
+    }
+
+    #[test]
+    fn draws_exits() {
+        let diagram = diagram(&game("board: 1 2 1\nstart: 0\ngoal: exit-right"));
+        let expected = [
+            " 0  1  2",
+            "[1][2][1]",
+            " S",
+            " +-->        0 -> 1",
+            "    +----->  1 -> exit",
+        ];
+        assert_eq!(diagram, expected.join("\n"));
+    }
+
+    #[test]
+    fn marks_any_position() {
+        let board = board(&game("board: 1 2 3 0 3 2\nstart: 0"), 4, '^');
+        let expected = [" 0  1  2  3  4  5", "[1][2][3][0][3][2]", "          G  ^"];
+        assert_eq!(board, expected.join("\n"));
+    }
+
+    #[test]
+    fn says_when_there_is_no_path() {
+        let diagram = diagram(&game("board: 2 0\nstart: 0"));
+        assert!(diagram.ends_with("no winning path"));
+    }
+}
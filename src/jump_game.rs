@@ -1,13 +1,115 @@
-use std::collections::HashSet;
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
 
-pub struct JumpGame {
+mod analysis;
+mod binary;
+mod boundary;
+mod cost;
+mod count;
+mod diagnosis;
+mod format;
+mod forward;
+mod game_ref;
+mod generator;
+mod goal;
+mod grid;
+#[cfg(test)]
+mod properties;
+mod repair;
+mod rule;
+mod scratch;
+#[cfg(feature = "serde")]
+mod serialization;
+mod session;
+mod teleport;
+
+pub use analysis::BoardAnalysis;
+pub use binary::{BinaryJumpGame, BinaryJumpGameError};
+pub use boundary::Boundary;
+pub use cost::CheapestPath;
+pub use count::PathCountError;
+pub use diagnosis::Diagnosis;
+pub use format::{
+    parse_puzzles, write_puzzles, Expectation, ParseError, ParseErrorKind, Puzzle, RuleKind,
+};
+pub use forward::ForwardJumpGame;
+pub use game_ref::{BoardValue, JumpGameRef};
+pub use generator::{JumpGameGenerator, Target};
+pub use goal::Goal;
+pub use grid::{Directions, GridJumpGame, GridJumpGameError, Position};
+pub use repair::Edit;
+pub use rule::{BidirectionalUpTo, ForwardUpTo, Jump, JumpRule, SymmetricExact};
+pub use scratch::Scratch;
+pub use session::{Direction, JumpGameSession, MoveError, ReplayError, SessionStatus};
+pub use teleport::TeleportJumpGame;
+
+#[derive(Debug, Clone)]
+pub struct JumpGame<R = SymmetricExact> {
     board: Vec<usize>,
     starting_index: usize,
+    rule: R,
+    boundary: Boundary,
+    goal: Goal,
+}
+
+/// # The reasons a JumpGame can fail validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JumpGameError {
+    /// The board has no elements.
+    EmptyBoard,
+    /// The starting index does not point at a cell on the board.
+    StartingIndexOutOfBounds {
+        starting_index: usize,
+        board_len: usize,
+    },
+    /// The board has no 0 to jump to.
+    MissingZero { board_len: usize },
+    /// No cell on the board satisfies the goal.
+    MissingGoal { board_len: usize },
+    /// One of the goal indices does not point at a cell on the board.
+    GoalIndexOutOfBounds { index: usize, board_len: usize },
+    /// No cell on the board holds the goal value.
+    MissingGoalValue { value: usize, board_len: usize },
 }
 
+impl fmt::Display for JumpGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JumpGameError::EmptyBoard => write!(f, "Board must have at least one element"),
+            JumpGameError::StartingIndexOutOfBounds {
+                starting_index,
+                board_len,
+            } => write!(
+                f,
+                "Starting index {starting_index} must be within bounds of the board (length {board_len})"
+            ),
+            JumpGameError::MissingZero { board_len } => {
+                write!(f, "Board must contain at least one 0 (length {board_len})")
+            }
+            JumpGameError::MissingGoal { board_len } => {
+                write!(f, "Board must contain at least one goal cell (length {board_len})")
+            }
+            JumpGameError::GoalIndexOutOfBounds { index, board_len } => write!(
+                f,
+                "Goal index {index} must be within bounds of the board (length {board_len})"
+            ),
+            JumpGameError::MissingGoalValue { value, board_len } => write!(
+                f,
+                "Board must contain at least one {value} to reach (length {board_len})"
+            ),
+        }
+    }
+}
+
+impl Error for JumpGameError {}
+
 impl JumpGame {
     /// # Creates a new JumpGame with the given board and starting position.
     ///
+    /// Panics if the board is invalid, see [`JumpGame::try_new`] for the fallible version.
+    ///
     /// ## Example
     /// ```
     /// # use rust_algorithms::jump_game::JumpGame;
@@ -31,21 +133,130 @@ impl JumpGame {
     /// JumpGame::new(vec![1,2,3], 0);
     /// ```
     pub fn new(board: Vec<usize>, starting_index: usize) -> Self {
-        if board.len() == 0 {
-            panic!("Board must have at least one element");
-        }
-        if starting_index >= board.len() {
-            panic!("Starting index must be within bounds of the board");
-        }
-        if !board.iter().any(|&x| x == 0) {
-            panic!("Board must contain at least one 0");
-        }
-        Self {
+        Self::try_new(board, starting_index).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new JumpGame, returning an error instead of panicking when the board is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGame, JumpGameError};
+    /// assert!(JumpGame::try_new(vec![1, 2, 3, 0, 3, 2], 0).is_ok());
+    /// assert_eq!(JumpGame::try_new(vec![], 0).err(), Some(JumpGameError::EmptyBoard));
+    /// assert_eq!(
+    ///     JumpGame::try_new(vec![1, 0], 3).err(),
+    ///     Some(JumpGameError::StartingIndexOutOfBounds { starting_index: 3, board_len: 2 })
+    /// );
+    /// assert_eq!(
+    ///     JumpGame::try_new(vec![1, 2, 3], 0).err(),
+    ///     Some(JumpGameError::MissingZero { board_len: 3 })
+    /// );
+    /// ```
+    pub fn try_new(board: Vec<usize>, starting_index: usize) -> Result<Self, JumpGameError> {
+        Self::try_with_goal(board, starting_index, Goal::ZeroCell)
+    }
+
+    /// # Creates a new JumpGame that is won by reaching `goal` instead of a 0.
+    ///
+    /// Panics if the board is invalid, see [`JumpGame::try_with_goal`] for the fallible version.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Goal, JumpGame};
+    /// // no 0 is needed when the goal is to jump off the right hand end
+    /// let game = JumpGame::with_goal(vec![2, 1, 3, 1], 0, Goal::ExitRight);
+    /// assert_eq!(game.shortest_path(), Some(vec![0, 2]));
+    /// assert_eq!(game.min_jumps(), Some(2));
+    /// ```
+    pub fn with_goal(board: Vec<usize>, starting_index: usize, goal: Goal) -> Self {
+        Self::try_with_goal(board, starting_index, goal).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new JumpGame that is won by reaching `goal`, returning an error instead of
+    /// panicking when the board is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use std::collections::BTreeSet;
+    /// # use rust_algorithms::jump_game::{Goal, JumpGame, JumpGameError};
+    /// assert!(JumpGame::try_with_goal(vec![1, 2, 3], 0, Goal::Value(3)).is_ok());
+    /// assert_eq!(
+    ///     JumpGame::try_with_goal(vec![1, 2, 3], 0, Goal::Value(4)).err(),
+    ///     Some(JumpGameError::MissingGoalValue { value: 4, board_len: 3 })
+    /// );
+    /// assert_eq!(
+    ///     JumpGame::try_with_goal(vec![1, 2, 3], 0, Goal::Index(BTreeSet::from([5]))).err(),
+    ///     Some(JumpGameError::GoalIndexOutOfBounds { index: 5, board_len: 3 })
+    /// );
+    /// ```
+    pub fn try_with_goal(
+        board: Vec<usize>,
+        starting_index: usize,
+        goal: Goal,
+    ) -> Result<Self, JumpGameError> {
+        validate(&board, starting_index, &goal)?;
+        Ok(Self {
             board,
             starting_index,
+            rule: SymmetricExact,
+            boundary: Boundary::Wall,
+            goal,
+        })
+    }
+
+    /// # Checks every starting index of the board for winnability in a single pass.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// assert_eq!(
+    ///     JumpGame::winnable_starts(&[1, 7, 3, 0, 3, 2]),
+    ///     vec![false, false, true, true, false, true]
+    /// );
+    /// ```
+    pub fn winnable_starts(board: &[usize]) -> Vec<bool> {
+        BoardAnalysis::new(board).winnable_starts()
+    }
+}
+
+impl<R: JumpRule> JumpGame<R> {
+    /// # Replaces the rule deciding where each cell can jump to.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{ForwardUpTo, JumpGame};
+    /// let game = JumpGame::new(vec![3, 1, 2, 5, 0], 0);
+    /// assert!(!game.is_winnable());
+    ///
+    /// let game = game.with_rule(ForwardUpTo);
+    /// assert!(game.is_winnable());
+    /// assert_eq!(game.min_jumps(), Some(2));
+    /// ```
+    pub fn with_rule<S: JumpRule>(self, rule: S) -> JumpGame<S> {
+        JumpGame {
+            board: self.board,
+            starting_index: self.starting_index,
+            rule,
+            boundary: self.boundary,
+            goal: self.goal,
         }
     }
 
+    /// # Changes what happens when a jump goes past either end of the board.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Boundary, JumpGame};
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert!(!game.is_winnable());
+    ///
+    /// let game = game.with_boundary(Boundary::Wrap);
+    /// assert_eq!(game.shortest_path(), Some(vec![0, 4, 2]));
+    /// ```
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self { boundary, ..self }
+    }
+
     /// # Checks to see if the JumpGame is winnable.
     ///
     /// ## Examples
@@ -64,47 +275,323 @@ impl JumpGame {
     /// assert!(!game.is_winnable());
     /// ```
     pub fn is_winnable(&self) -> bool {
-        let mut stack = Vec::<isize>::new();
-        let mut visited = HashSet::<isize>::new();
+        self.view().is_winnable()
+    }
 
-        stack.push(self.starting_index as isize);
+    /// # Checks to see if the JumpGame is winnable, reusing the buffers in `scratch`.
+    ///
+    /// Once `scratch` has grown to fit the board this does not allocate, which matters when
+    /// screening lots of large boards.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGame, Scratch};
+    /// let mut scratch = Scratch::new();
+    /// for starting_index in 0..6 {
+    ///     let game = JumpGame::new(vec![1, 7, 3, 0, 3, 2], starting_index);
+    ///     assert_eq!(game.is_winnable_with(&mut scratch), game.is_winnable());
+    /// }
+    /// ```
+    pub fn is_winnable_with(&self, scratch: &mut Scratch) -> bool {
+        self.view().is_winnable_with(scratch)
+    }
 
-        while let Some(current_index) = stack.pop() {
-            if visited.contains(&current_index) {
-                // we've been here already - prevent infinite loops
-                continue;
-            }
-            if current_index < 0 {
-                // out of bounds left
-                visited.insert(current_index);
-                continue;
-            }
+    /// # Finds a winning path through the JumpGame, if one exists.
+    ///
+    /// The path starts at `starting_index` and ends on a goal cell, with each step being a single
+    /// jump. It is not necessarily the shortest path. When the goal is to leave the board the
+    /// path ends on the cell that jumps off.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 0);
+    /// assert_eq!(game.solve(), Some(vec![0, 1, 3]));
+    /// ```
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert_eq!(game.solve(), None);
+    /// ```
+    pub fn solve(&self) -> Option<Vec<usize>> {
+        self.view().solve()
+    }
 
-            match self.board.get(current_index as usize) {
-                Some(0) => {
-                    // WINNER!
-                    return true;
-                }
-                Some(value) => {
-                    // not a 0, but still in bounds
-                    stack.push(current_index - (*value as isize));
-                    stack.push(current_index + (*value as isize));
-                }
-                None => {
-                    // out of bounds right
+    /// # Finds the fewest number of jumps needed to reach a goal from the starting index.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 1, 1, 1, 0], 0);
+    /// assert_eq!(game.min_jumps(), Some(4));
+    /// ```
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert_eq!(game.min_jumps(), None);
+    /// ```
+    pub fn min_jumps(&self) -> Option<usize> {
+        self.view().min_jumps()
+    }
+
+    /// # Finds a path with the fewest jumps from the starting index to a goal.
+    ///
+    /// Unlike [`JumpGame::solve`], this searches breadth first so the nearest goal is always
+    /// found. When the goal is to leave the board the path ends on the cell that jumps off, so
+    /// it is one index shorter than [`JumpGame::min_jumps`] suggests.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![0, 1, 2, 1, 0], 1);
+    /// assert_eq!(game.solve(), Some(vec![1, 2, 4]));
+    /// assert_eq!(game.shortest_path(), Some(vec![1, 0]));
+    /// ```
+    pub fn shortest_path(&self) -> Option<Vec<usize>> {
+        self.view().shortest_path()
+    }
+
+    /// # Works out winnability and distance-to-the-nearest-goal for every index of the board.
+    ///
+    /// The starting index of the game is ignored, see [`BoardAnalysis`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 0);
+    /// let analysis = game.analyze();
+    /// assert!(analysis.is_winnable(4));
+    /// assert_eq!(analysis.distance(4), Some(2));
+    /// ```
+    pub fn analyze(&self) -> BoardAnalysis {
+        self.view().analyze()
+    }
+
+    /// # The values on the board.
+    pub fn board(&self) -> &[usize] {
+        &self.board
+    }
+
+    /// # The index the game starts from.
+    pub fn starting_index(&self) -> usize {
+        self.starting_index
+    }
+
+    /// # The rule deciding where each cell can jump to.
+    pub fn rule(&self) -> &R {
+        &self.rule
+    }
+
+    /// # What happens when a jump goes past either end of the board.
+    pub fn boundary(&self) -> Boundary {
+        self.boundary
+    }
+
+    /// # What counts as winning the game.
+    pub fn goal(&self) -> &Goal {
+        &self.goal
+    }
+
+    /// # Borrows the game as a [`JumpGameRef`].
+    pub fn view(&self) -> JumpGameRef<'_, usize, &R> {
+        JumpGameRef {
+            board: &self.board,
+            starting_index: self.starting_index,
+            rule: &self.rule,
+            boundary: self.boundary,
+            goal: Cow::Borrowed(&self.goal),
+        }
+    }
+}
+
+/// # A jump game where each landing scores points, better known as Jump Game VI.
+///
+/// The player starts on the first index and can jump anywhere from 1 to `max_jump` indices
+/// forward, collecting the value of the starting cell and every cell they land on. Values may
+/// be negative, and the aim is to reach the last index with the highest score.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::ScoringJumpGame;
+/// let game = ScoringJumpGame::new(vec![1, -1, -2, 4, -7, 3], 2);
+/// assert_eq!(game.max_score(), 7);
+/// assert_eq!(game.best_path().path, vec![0, 1, 3, 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoringJumpGame {
+    board: Vec<i64>,
+    max_jump: usize,
+}
+
+/// # The highest scoring way to win a ScoringJumpGame, as found by
+/// [`ScoringJumpGame::best_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoredPath {
+    /// The total of every value along the path.
+    pub score: i128,
+    /// The indices visited, from the first index to the last.
+    pub path: Vec<usize>,
+}
+
+/// # The reasons a ScoringJumpGame can fail validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScoringJumpGameError {
+    /// The board fails the validation every jump game shares.
+    Board(JumpGameError),
+    /// The longest jump allowed is 0, so the player can never move.
+    ZeroMaxJump,
+}
+
+impl fmt::Display for ScoringJumpGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScoringJumpGameError::Board(error) => write!(f, "{error}"),
+            ScoringJumpGameError::ZeroMaxJump => write!(f, "Max jump must be at least 1"),
+        }
+    }
+}
+
+impl Error for ScoringJumpGameError {}
+
+impl From<JumpGameError> for ScoringJumpGameError {
+    fn from(error: JumpGameError) -> Self {
+        ScoringJumpGameError::Board(error)
+    }
+}
+
+impl ScoringJumpGame {
+    /// # Creates a new ScoringJumpGame with the given board and longest jump.
+    ///
+    /// Panics if the board is empty or `max_jump` is 0, see [`ScoringJumpGame::try_new`] for
+    /// the fallible version.
+    pub fn new(board: Vec<i64>, max_jump: usize) -> Self {
+        Self::try_new(board, max_jump).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new ScoringJumpGame, returning an error instead of panicking when the board
+    /// is empty or `max_jump` is 0.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGameError, ScoringJumpGame, ScoringJumpGameError};
+    /// assert!(ScoringJumpGame::try_new(vec![-1], 1).is_ok());
+    /// assert_eq!(
+    ///     ScoringJumpGame::try_new(vec![], 1),
+    ///     Err(ScoringJumpGameError::Board(JumpGameError::EmptyBoard))
+    /// );
+    /// assert_eq!(
+    ///     ScoringJumpGame::try_new(vec![1, 2], 0),
+    ///     Err(ScoringJumpGameError::ZeroMaxJump)
+    /// );
+    /// ```
+    pub fn try_new(board: Vec<i64>, max_jump: usize) -> Result<Self, ScoringJumpGameError> {
+        validate_board(&board)?;
+        if max_jump == 0 {
+            return Err(ScoringJumpGameError::ZeroMaxJump);
+        }
+        Ok(Self { board, max_jump })
+    }
+
+    /// # The values on the board.
+    pub fn board(&self) -> &[i64] {
+        &self.board
+    }
+
+    /// # The longest jump forward the player can make.
+    pub fn max_jump(&self) -> usize {
+        self.max_jump
+    }
+
+    /// # Finds the highest score the player can have on reaching the last index.
+    pub fn max_score(&self) -> i128 {
+        self.best_path().score
+    }
+
+    /// # Finds the highest scoring path to the last index.
+    ///
+    /// The best score for each index is its own value plus the best score of the previous
+    /// `max_jump` indices. Those are kept in a deque with their best scores in decreasing order,
+    /// so the front is always the one to jump from, for O(n) time overall.
+    ///
+    /// Scores are added up as `i128`, so even a board full of `i64::MAX` can't overflow them.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::ScoringJumpGame;
+    /// let game = ScoringJumpGame::new(vec![10, -5, -2, 4, 0, 3], 3);
+    /// let best = game.best_path();
+    /// assert_eq!(best.score, 17);
+    /// assert_eq!(best.path, vec![0, 3, 4, 5]);
+    /// ```
+    pub fn best_path(&self) -> ScoredPath {
+        let len = self.board.len();
+        let mut scores = Vec::with_capacity(len);
+        let mut previous = Vec::with_capacity(len);
+        let mut window = VecDeque::with_capacity(self.max_jump.min(len));
+        scores.push(i128::from(self.board[0]));
+        previous.push(0);
+        window.push_back(0);
+
+        for (index, &value) in self.board.iter().enumerate().skip(1) {
+            while let Some(&front) = window.front() {
+                if index - front <= self.max_jump {
+                    break;
                 }
+                window.pop_front();
             }
+            // the window is never empty, as the index before this one is always in it
+            let best = window[0];
+            let score = scores[best] + i128::from(value);
+            scores.push(score);
+            previous.push(best);
 
-            visited.insert(current_index);
+            while window.back().is_some_and(|&back| scores[back] <= score) {
+                window.pop_back();
+            }
+            window.push_back(index);
         }
 
-        false
+        let mut path = vec![len - 1];
+        while path[path.len() - 1] != 0 {
+            path.push(previous[path[path.len() - 1]]);
+        }
+        path.reverse();
+        ScoredPath {
+            score: scores[len - 1],
+            path,
+        }
+    }
+}
+
+/// Checks the parts of a board every jump game needs.
+fn validate_board<T>(board: &[T]) -> Result<(), JumpGameError> {
+    if board.is_empty() {
+        return Err(JumpGameError::EmptyBoard);
+    }
+    Ok(())
+}
+
+/// Makes sure a board, starting index, and goal make up a playable game.
+fn validate<T: BoardValue>(
+    board: &[T],
+    starting_index: usize,
+    goal: &Goal,
+) -> Result<(), JumpGameError> {
+    validate_board(board)?;
+    if starting_index >= board.len() {
+        return Err(JumpGameError::StartingIndexOutOfBounds {
+            starting_index,
+            board_len: board.len(),
+        });
     }
+    goal.validate(board)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::BTreeSet;
     use test_case::test_case;
 
     #[test]
@@ -136,4 +623,249 @@ mod tests {
         let game = JumpGame::new(board, starting_index);
         assert_eq!(game.is_winnable(), expected);
     }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, Some(vec![0, 1, 3]))]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 3, Some(vec![3]))]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 5, Some(vec![5, 3]))]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 0, None)]
+    #[test_case(vec![1, 1, 1, 1, 0], 0, Some(vec![0, 1, 2, 3, 4]))]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 5, Some(vec![5, 3]))]
+    fn solve_cases(board: Vec<usize>, starting_index: usize, expected: Option<Vec<usize>>) {
+        let game = JumpGame::new(board, starting_index);
+        assert_eq!(game.solve(), expected);
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2])]
+    #[test_case(vec![1, 7, 3, 0, 3, 2])]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2])]
+    fn solve_returns_a_valid_path_whenever_the_board_is_winnable(board: Vec<usize>) {
+        for starting_index in 0..board.len() {
+            let game = JumpGame::new(board.clone(), starting_index);
+            match game.solve() {
+                Some(path) => {
+                    assert!(game.is_winnable());
+                    assert_eq!(path.first(), Some(&starting_index));
+                    assert_eq!(board[*path.last().unwrap()], 0);
+                    for hop in path.windows(2) {
+                        assert_eq!(hop[0].abs_diff(hop[1]), board[hop[0]]);
+                    }
+                }
+                None => assert!(!game.is_winnable()),
+            }
+        }
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, Some(2))]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 3, Some(0))]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 4, Some(2))]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 0, None)]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 5, Some(1))]
+    #[test_case(vec![1, 1, 1, 1, 0], 0, Some(4))]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 5, Some(1))]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 6, None)]
+    fn min_jumps_cases(board: Vec<usize>, starting_index: usize, expected: Option<usize>) {
+        let game = JumpGame::new(board, starting_index);
+        assert_eq!(game.min_jumps(), expected);
+    }
+
+    #[test_case(vec![3, 1, 1, 1, 0, 5, 0], 3, vec![3, 4])]
+    #[test_case(vec![0, 1, 2, 1, 0], 1, vec![1, 0])]
+    #[test_case(vec![2, 1, 1, 0, 1, 1, 0], 0, vec![0, 2, 3])]
+    fn shortest_path_finds_the_nearest_zero(
+        board: Vec<usize>,
+        starting_index: usize,
+        expected: Vec<usize>,
+    ) {
+        let game = JumpGame::new(board, starting_index);
+        assert_eq!(game.shortest_path(), Some(expected));
+    }
+
+    #[test_case(vec![3, 1, 2, 5, 0], 0, Some(vec![0, 2, 4]))]
+    #[test_case(vec![2, 0, 1, 4, 1], 4, None)]
+    #[test_case(vec![1, 1, 0], 0, Some(vec![0, 1, 2]))]
+    fn forward_rule_only_jumps_right(
+        board: Vec<usize>,
+        starting_index: usize,
+        expected: Option<Vec<usize>>,
+    ) {
+        let game = JumpGame::new(board, starting_index).with_rule(ForwardUpTo);
+        assert_eq!(game.is_winnable(), expected.is_some());
+        assert_eq!(game.shortest_path(), expected);
+    }
+
+    #[test_case(vec![2, 5, 1, 7, 0], 3, Some(vec![3, 4]))]
+    #[test_case(vec![3, 2, 2, 0], 0, Some(vec![0, 3]))]
+    #[test_case(vec![0, 5, 5, 5], 3, Some(vec![3, 0]))]
+    fn bidirectional_rule_jumps_any_distance(
+        board: Vec<usize>,
+        starting_index: usize,
+        expected: Option<Vec<usize>>,
+    ) {
+        let game = JumpGame::new(board, starting_index).with_rule(BidirectionalUpTo);
+        assert_eq!(game.shortest_path(), expected);
+    }
+
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 0, Some(2))]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 1, Some(3))]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 4, Some(4))]
+    #[test_case(vec![1, 2, 0, 3, 2], 0, Some(2))]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 2, Some(1))]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 6, Some(3))]
+    #[test_case(vec![2, 0, 2, 2], 0, None)]
+    fn wrapping_makes_unwinnable_boards_winnable(
+        board: Vec<usize>,
+        starting_index: usize,
+        expected: Option<usize>,
+    ) {
+        let game = JumpGame::new(board, starting_index);
+        assert!(!game.is_winnable());
+
+        let game = game.with_boundary(Boundary::Wrap);
+        assert_eq!(game.is_winnable(), expected.is_some());
+        assert_eq!(game.solve().is_some(), expected.is_some());
+        assert_eq!(game.min_jumps(), expected);
+        assert_eq!(game.analyze().distance(starting_index), expected);
+    }
+
+    #[test_case(vec![2, 1, 3, 1], 0, Some(2))]
+    #[test_case(vec![1, 1, 1], 0, Some(3))]
+    #[test_case(vec![1, 1, 5, 1], 3, Some(1))]
+    #[test_case(vec![2, 9, 0], 0, None)]
+    fn exit_right_goal_cases(board: Vec<usize>, starting_index: usize, expected: Option<usize>) {
+        let game = JumpGame::with_goal(board, starting_index, Goal::ExitRight);
+        assert_eq!(game.is_winnable(), expected.is_some());
+        assert_eq!(game.min_jumps(), expected);
+        assert_eq!(game.shortest_path().map(|path| path.len()), expected);
+        assert_eq!(game.analyze().distance(starting_index), expected);
+    }
+
+    #[test]
+    fn exit_right_goal_cannot_be_reached_on_a_wrapping_board() {
+        let game = JumpGame::with_goal(vec![1, 1, 1], 0, Goal::ExitRight);
+        assert!(!game.with_boundary(Boundary::Wrap).is_winnable());
+    }
+
+    #[test_case(Goal::Index(BTreeSet::from([2, 3])), Some(vec![0, 1, 3]))]
+    #[test_case(Goal::Index(BTreeSet::from([1, 4])), Some(vec![0, 1]))]
+    #[test_case(Goal::Value(3), Some(vec![0, 1, 3]))]
+    #[test_case(Goal::Value(1), Some(vec![0]))]
+    #[test_case(Goal::custom(|index, _| index == 5), None)]
+    fn other_goal_cases(goal: Goal, expected: Option<Vec<usize>>) {
+        let game = JumpGame::with_goal(vec![1, 2, 2, 3, 2, 9], 0, goal);
+        assert_eq!(game.shortest_path(), expected);
+        assert_eq!(game.solve().is_some(), expected.is_some());
+    }
+
+    #[test_case(vec![usize::MAX, 0], 0)]
+    #[test_case(vec![0, isize::MAX as usize], 1)]
+    #[test_case(vec![0, usize::MAX], 1)]
+    fn huge_jumps_leave_the_board(board: Vec<usize>, starting_index: usize) {
+        let game = JumpGame::new(board, starting_index);
+        assert!(!game.is_winnable());
+        assert_eq!(game.shortest_path(), None);
+
+        let game = JumpGame::with_goal(game.board().to_vec(), starting_index, Goal::ExitRight);
+        assert_eq!(game.min_jumps(), Some(1));
+    }
+
+    #[test]
+    fn long_jumps_wrap_around_exactly() {
+        // usize::MAX is odd, so jumping it either way around a board of 2 swaps sides
+        let game = JumpGame::new(vec![usize::MAX, 0], 0).with_boundary(Boundary::Wrap);
+        assert_eq!(game.shortest_path(), Some(vec![0, 1]));
+
+        let game =
+            JumpGame::new(vec![0, usize::MAX, usize::MAX - 1], 2).with_boundary(Boundary::Wrap);
+        assert!(game.is_winnable());
+        // while usize::MAX is a multiple of 3, so it jumps all the way around to where it started
+        assert_eq!(game.analyze().distance(1), None);
+    }
+
+    #[test]
+    fn long_jumps_only_check_the_board() {
+        let game = JumpGame::new(vec![400_000_000, 0], 0).with_rule(ForwardUpTo);
+        assert!(game.is_winnable());
+        assert_eq!(game.analyze().distance(0), Some(1));
+        assert_eq!(game.min_edits(), Some(vec![]));
+
+        let game = JumpGame::new(vec![400_000_000, 0], 0).with_rule(BidirectionalUpTo);
+        assert_eq!(
+            game.cheapest_path(|_, _, _| 1).map(|path| path.cost),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn zeros_are_not_special_for_other_goals() {
+        let game = JumpGame::with_goal(vec![1, 0, 2], 0, Goal::Index(BTreeSet::from([2])));
+        assert!(!game.is_winnable());
+    }
+
+    #[test_case(vec![], 0, JumpGameError::EmptyBoard)]
+    #[test_case(vec![1, 0], 2, JumpGameError::StartingIndexOutOfBounds { starting_index: 2, board_len: 2 })]
+    #[test_case(vec![1, 2, 3], 0, JumpGameError::MissingZero { board_len: 3 })]
+    fn try_new_rejects_invalid_boards(
+        board: Vec<usize>,
+        starting_index: usize,
+        expected: JumpGameError,
+    ) {
+        assert_eq!(
+            JumpGame::try_new(board, starting_index).err(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn new_panics_with_the_error_message() {
+        let result = std::panic::catch_unwind(|| JumpGame::new(vec![1, 2, 3], 0));
+        let payload = result.err().unwrap();
+        assert_eq!(
+            payload.downcast_ref::<String>().unwrap(),
+            &JumpGameError::MissingZero { board_len: 3 }.to_string()
+        );
+    }
+
+    #[test_case(vec![1, -1, -2, 4, -7, 3], 2, 7, vec![0, 1, 3, 5])]
+    #[test_case(vec![10, -5, -2, 4, 0, 3], 3, 17, vec![0, 3, 4, 5])]
+    #[test_case(vec![1, -5, -20, 4, -1, 3, -6, -3], 2, 0, vec![0, 1, 3, 5, 7])]
+    #[test_case(vec![-3], 1, -3, vec![0])]
+    #[test_case(vec![5, -1, -1, -1, 2], 1, 4, vec![0, 1, 2, 3, 4])]
+    #[test_case(vec![5, -1, -1, -1, 2], 10, 7, vec![0, 4])]
+    #[test_case(vec![-1, -2, -3], 2, -4, vec![0, 2])]
+    fn scoring_cases(board: Vec<i64>, max_jump: usize, score: i128, path: Vec<usize>) {
+        let game = ScoringJumpGame::new(board, max_jump);
+        assert_eq!(game.max_score(), score);
+        assert_eq!(game.best_path(), ScoredPath { score, path });
+    }
+
+    #[test]
+    fn scores_do_not_overflow() {
+        let game = ScoringJumpGame::new(vec![i64::MAX, 1], 1);
+        assert_eq!(game.max_score(), i128::from(i64::MAX) + 1);
+
+        let game = ScoringJumpGame::new(vec![i64::MIN; 3], 1);
+        assert_eq!(game.max_score(), 3 * i128::from(i64::MIN));
+    }
+
+    #[test]
+    fn scoring_games_share_board_validation() {
+        assert_eq!(
+            ScoringJumpGame::try_new(vec![], 2),
+            Err(ScoringJumpGameError::Board(JumpGameError::EmptyBoard))
+        );
+        assert_eq!(
+            ScoringJumpGame::try_new(vec![1], 0),
+            Err(ScoringJumpGameError::ZeroMaxJump)
+        );
+    }
+
+    #[test]
+    fn scoring_game_errors_have_messages() {
+        let error = ScoringJumpGame::try_new(vec![], 2).unwrap_err();
+        assert_eq!(error.to_string(), JumpGameError::EmptyBoard.to_string());
+        assert_eq!(
+            ScoringJumpGameError::ZeroMaxJump.to_string(),
+            "Max jump must be at least 1"
+        );
+    }
 }
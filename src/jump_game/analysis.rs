@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+
+use super::{BoardValue, Boundary, Goal, JumpRule, SymmetricExact};
+
+/// # Winnability and distance-to-the-nearest-goal for every index of a board.
+///
+/// Built with a single breadth first search that starts from every goal at once and walks the jumps
+/// backwards, so analysing the whole board costs the same as a single call to
+/// [`JumpGame::shortest_path`](super::JumpGame::shortest_path).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BoardAnalysis {
+    distances: Vec<Option<usize>>,
+}
+
+impl BoardAnalysis {
+    /// # Analyses every starting index of the given board.
+    ///
+    /// The board does not need to be a valid JumpGame, a board without any 0 simply has no
+    /// winnable indices.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::BoardAnalysis;
+    /// let analysis = BoardAnalysis::new(&[1, 7, 3, 0, 3, 2]);
+    /// assert_eq!(
+    ///     analysis.distances(),
+    ///     &[None, None, Some(2), Some(0), None, Some(1)]
+    /// );
+    /// ```
+    pub fn new(board: &[usize]) -> Self {
+        Self::with_rule(board, &SymmetricExact)
+    }
+
+    /// # Analyses every starting index of the given board, jumping according to `rule`.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{BoardAnalysis, ForwardUpTo};
+    /// let analysis = BoardAnalysis::with_rule(&[3, 2, 1, 0, 4], &ForwardUpTo);
+    /// assert_eq!(analysis.winnable_starts(), vec![true, true, true, true, false]);
+    /// ```
+    pub fn with_rule<R: JumpRule>(board: &[usize], rule: &R) -> Self {
+        Self::build(board, rule, Boundary::Wall, &Goal::ZeroCell)
+    }
+
+    pub(super) fn build<T: BoardValue, R: JumpRule>(
+        board: &[T],
+        rule: &R,
+        boundary: Boundary,
+        goal: &Goal,
+    ) -> Self {
+        // reversed[i] holds every index that can jump directly to i, with the extra index at the
+        // end standing in for jumping off the board to win
+        let exit = board.len();
+        let mut reversed = vec![Vec::<usize>::new(); board.len() + 1];
+        for (index, value) in board.iter().enumerate() {
+            if goal.is_goal_cell(index, value.to_usize()) {
+                // the game ends on a goal, so there are no jumps out of it
+                continue;
+            }
+            rule.targets(
+                index,
+                value.to_jump_length(board.len()),
+                board.len(),
+                |jump| {
+                    if let Some(target) = boundary.resolve(jump, board.len()) {
+                        reversed[target].push(index);
+                    } else if goal.is_winning_exit(jump.target(), board.len()) {
+                        reversed[exit].push(index);
+                    }
+                },
+            );
+        }
+
+        let mut distances = vec![None; board.len() + 1];
+        let mut queue = VecDeque::<usize>::new();
+        for (index, value) in board.iter().map(|value| value.to_usize()).enumerate() {
+            if goal.is_goal_cell(index, value) {
+                distances[index] = Some(0);
+                queue.push_back(index);
+            }
+        }
+        distances[exit] = Some(0);
+        queue.push_back(exit);
+
+        while let Some(current_index) = queue.pop_front() {
+            let distance = distances[current_index].map(|distance| distance + 1);
+            for &previous_index in &reversed[current_index] {
+                if distances[previous_index].is_none() {
+                    distances[previous_index] = distance;
+                    queue.push_back(previous_index);
+                }
+            }
+        }
+
+        distances.truncate(board.len());
+        Self { distances }
+    }
+
+    /// # Checks to see if the board is winnable when starting from `index`.
+    ///
+    /// Indices past the end of the board are never winnable.
+    pub fn is_winnable(&self, index: usize) -> bool {
+        self.distance(index).is_some()
+    }
+
+    /// # The fewest jumps needed to reach a goal from `index`, if it can be reached at all.
+    pub fn distance(&self, index: usize) -> Option<usize> {
+        self.distances.get(index).copied().flatten()
+    }
+
+    /// # The distance to the nearest goal for every index of the board.
+    pub fn distances(&self) -> &[Option<usize>] {
+        &self.distances
+    }
+
+    /// # Whether each index of the board is a winnable starting index.
+    pub fn winnable_starts(&self) -> Vec<bool> {
+        self.distances.iter().map(Option::is_some).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{BidirectionalUpTo, ForwardUpTo, JumpGame};
+    use test_case::test_case;
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2])]
+    #[test_case(vec![1, 7, 3, 0, 3, 2])]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2])]
+    #[test_case(vec![1, 1, 1, 1, 0])]
+    #[test_case(vec![0, 1, 2, 1, 0])]
+    #[test_case(vec![3, 1, 1, 1, 0, 5, 0])]
+    fn agrees_with_the_single_start_solvers(board: Vec<usize>) {
+        let analysis = BoardAnalysis::new(&board);
+        for starting_index in 0..board.len() {
+            let game = JumpGame::new(board.clone(), starting_index);
+            assert_eq!(analysis.is_winnable(starting_index), game.is_winnable());
+            assert_eq!(analysis.distance(starting_index), game.min_jumps());
+        }
+    }
+
+    #[test_case(vec![2, 3, 1, 1, 4, 0])]
+    #[test_case(vec![3, 2, 1, 0, 4, 0])]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2])]
+    fn agrees_with_the_single_start_solvers_for_other_rules(board: Vec<usize>) {
+        let forward = BoardAnalysis::with_rule(&board, &ForwardUpTo);
+        let bidirectional = BoardAnalysis::with_rule(&board, &BidirectionalUpTo);
+        for starting_index in 0..board.len() {
+            let game = JumpGame::new(board.clone(), starting_index);
+            let forward_game = game.clone().with_rule(ForwardUpTo);
+            assert_eq!(forward.distance(starting_index), forward_game.min_jumps());
+            let bidirectional_game = game.with_rule(BidirectionalUpTo);
+            assert_eq!(
+                bidirectional.distance(starting_index),
+                bidirectional_game.min_jumps()
+            );
+        }
+    }
+
+    #[test_case(vec![], vec![])]
+    #[test_case(vec![1, 2, 3], vec![false, false, false])]
+    #[test_case(vec![0], vec![true])]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], vec![false, false, false, true, false, true, false])]
+    fn winnable_starts_cases(board: Vec<usize>, expected: Vec<bool>) {
+        assert_eq!(BoardAnalysis::new(&board).winnable_starts(), expected);
+    }
+
+    #[test]
+    fn out_of_bounds_indices_are_not_winnable() {
+        let analysis = BoardAnalysis::new(&[1, 0]);
+        assert!(!analysis.is_winnable(2));
+        assert_eq!(analysis.distance(2), None);
+    }
+}
@@ -0,0 +1,222 @@
+use std::error::Error;
+use std::fmt;
+
+use super::{validate_board, JumpGameError};
+
+/// # A jump game over a binary string, better known as Jump Game VII.
+///
+/// The player starts on the first index and can only land on `'0'` cells, with every jump going
+/// between `min_jump` and `max_jump` indices forward. Reaching the last index wins.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::BinaryJumpGame;
+/// let game = BinaryJumpGame::new("011010", 2, 3);
+/// assert!(game.is_winnable());
+/// assert_eq!(game.solve(), Some(vec![0, 3, 5]));
+///
+/// let game = BinaryJumpGame::new("01101110", 2, 3);
+/// assert!(!game.is_winnable());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BinaryJumpGame {
+    open: Vec<bool>,
+    min_jump: usize,
+    max_jump: usize,
+}
+
+/// # The reasons a BinaryJumpGame can fail validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BinaryJumpGameError {
+    /// The board fails the validation every jump game shares.
+    Board(JumpGameError),
+    /// A cell of the board is neither `'0'` nor `'1'`.
+    InvalidCell { index: usize, cell: char },
+    /// The first cell of the board is a `'1'`, which can't be stood on.
+    BlockedStart,
+    /// The shortest jump allowed is 0 or longer than the longest.
+    InvalidJumpRange { min_jump: usize, max_jump: usize },
+}
+
+impl fmt::Display for BinaryJumpGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BinaryJumpGameError::Board(error) => write!(f, "{error}"),
+            BinaryJumpGameError::InvalidCell { index, cell } => {
+                write!(f, "Cell {index} must be '0' or '1', not {cell:?}")
+            }
+            BinaryJumpGameError::BlockedStart => write!(f, "Board must start on a '0'"),
+            BinaryJumpGameError::InvalidJumpRange { min_jump, max_jump } => write!(
+                f,
+                "Min jump {min_jump} must be at least 1 and no more than max jump {max_jump}"
+            ),
+        }
+    }
+}
+
+impl Error for BinaryJumpGameError {}
+
+impl From<JumpGameError> for BinaryJumpGameError {
+    fn from(error: JumpGameError) -> Self {
+        BinaryJumpGameError::Board(error)
+    }
+}
+
+impl BinaryJumpGame {
+    /// # Creates a new BinaryJumpGame with the given board and jump range.
+    ///
+    /// Panics if the board or jump range is invalid, see [`BinaryJumpGame::try_new`] for the
+    /// fallible version.
+    pub fn new(board: &str, min_jump: usize, max_jump: usize) -> Self {
+        Self::try_new(board, min_jump, max_jump).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new BinaryJumpGame, returning an error instead of panicking when the board
+    /// or jump range is invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::{BinaryJumpGame, BinaryJumpGameError, JumpGameError};
+    /// assert!(BinaryJumpGame::try_new("0110", 1, 3).is_ok());
+    /// assert_eq!(
+    ///     BinaryJumpGame::try_new("", 1, 3),
+    ///     Err(BinaryJumpGameError::Board(JumpGameError::EmptyBoard))
+    /// );
+    /// assert_eq!(
+    ///     BinaryJumpGame::try_new("0120", 1, 3),
+    ///     Err(BinaryJumpGameError::InvalidCell { index: 2, cell: '2' })
+    /// );
+    /// assert_eq!(BinaryJumpGame::try_new("1000", 1, 3), Err(BinaryJumpGameError::BlockedStart));
+    /// assert_eq!(
+    ///     BinaryJumpGame::try_new("0000", 3, 2),
+    ///     Err(BinaryJumpGameError::InvalidJumpRange { min_jump: 3, max_jump: 2 })
+    /// );
+    /// ```
+    pub fn try_new(
+        board: &str,
+        min_jump: usize,
+        max_jump: usize,
+    ) -> Result<Self, BinaryJumpGameError> {
+        let open = board
+            .chars()
+            .enumerate()
+            .map(|(index, cell)| match cell {
+                '0' => Ok(true),
+                '1' => Ok(false),
+                cell => Err(BinaryJumpGameError::InvalidCell { index, cell }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        validate_board(&open)?;
+        if !open[0] {
+            return Err(BinaryJumpGameError::BlockedStart);
+        }
+        if min_jump == 0 || min_jump > max_jump {
+            return Err(BinaryJumpGameError::InvalidJumpRange { min_jump, max_jump });
+        }
+        Ok(Self {
+            open,
+            min_jump,
+            max_jump,
+        })
+    }
+
+    /// # Which cells can be landed on, `true` for each `'0'` on the board.
+    pub fn open_cells(&self) -> &[bool] {
+        &self.open
+    }
+
+    /// # The shortest jump the player can make.
+    pub fn min_jump(&self) -> usize {
+        self.min_jump
+    }
+
+    /// # The longest jump the player can make.
+    pub fn max_jump(&self) -> usize {
+        self.max_jump
+    }
+
+    /// # Checks to see if the last index can be reached.
+    pub fn is_winnable(&self) -> bool {
+        self.solve().is_some()
+    }
+
+    /// # Finds a path to the last index.
+    ///
+    /// The cells that can jump to an index are the window from `index - max_jump` to
+    /// `index - min_jump`. As the window slides forward it keeps track of the latest reachable
+    /// cell to have entered it, and the index is reachable if that cell hasn't left the window
+    /// yet, which takes O(n) time overall.
+    pub fn solve(&self) -> Option<Vec<usize>> {
+        let len = self.open.len();
+        let mut previous = vec![None; len];
+        // the latest reachable index to have entered the window
+        let mut latest = Some(0);
+        let mut reachable = vec![false; len];
+        reachable[0] = true;
+
+        for index in 1..len {
+            if let Some(entering) = index.checked_sub(self.min_jump) {
+                if reachable[entering] {
+                    latest = Some(entering);
+                }
+            }
+            if index < self.min_jump || !self.open[index] {
+                continue;
+            }
+            if let Some(from) = latest.filter(|&from| index - from <= self.max_jump) {
+                reachable[index] = true;
+                previous[index] = Some(from);
+            }
+        }
+
+        if !reachable[len - 1] {
+            return None;
+        }
+        let mut path = vec![len - 1];
+        while let Some(from) = previous[path[path.len() - 1]] {
+            path.push(from);
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case("011010", 2, 3, Some(vec![0, 3, 5]))]
+    #[test_case("01101110", 2, 3, None)]
+    #[test_case("0", 1, 1, Some(vec![0]))]
+    #[test_case("00", 2, 2, None)]
+    #[test_case("0000", 1, 1, Some(vec![0, 1, 2, 3]))]
+    #[test_case("00000", 2, 4, Some(vec![0, 2, 4]))]
+    #[test_case("0000000001", 1, 9, None)]
+    #[test_case("0111110", 6, 6, Some(vec![0, 6]))]
+    #[test_case("0111110", 1, 5, None)]
+    #[test_case("00111010", 1, 3, None)]
+    #[test_case("00110010", 1, 3, Some(vec![0, 1, 4, 5, 7]))]
+    fn test_cases(board: &str, min_jump: usize, max_jump: usize, expected: Option<Vec<usize>>) {
+        let game = BinaryJumpGame::new(board, min_jump, max_jump);
+        assert_eq!(game.is_winnable(), expected.is_some());
+        assert_eq!(game.solve(), expected);
+    }
+
+    #[test_case("", 1, 1, BinaryJumpGameError::Board(JumpGameError::EmptyBoard))]
+    #[test_case("0a", 1, 1, BinaryJumpGameError::InvalidCell { index: 1, cell: 'a' })]
+    #[test_case("10", 1, 1, BinaryJumpGameError::BlockedStart)]
+    #[test_case("00", 0, 1, BinaryJumpGameError::InvalidJumpRange { min_jump: 0, max_jump: 1 })]
+    #[test_case("00", 2, 1, BinaryJumpGameError::InvalidJumpRange { min_jump: 2, max_jump: 1 })]
+    fn try_new_rejects_invalid_boards(
+        board: &str,
+        min_jump: usize,
+        max_jump: usize,
+        expected: BinaryJumpGameError,
+    ) {
+        assert_eq!(
+            BinaryJumpGame::try_new(board, min_jump, max_jump).err(),
+            Some(expected)
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use super::Jump;
+
+/// # What happens when a jump goes past either end of the board.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Boundary {
+    /// Jumping past either end leaves the board, which is a dead end.
+    #[default]
+    Wall,
+    /// The board is a ring, jumping past one end comes back around from the other.
+    Wrap,
+}
+
+impl Boundary {
+    /// Converts a jump into an index on a board of length `len`, or `None` if the jump left the
+    /// board.
+    pub(crate) fn resolve(self, jump: Jump, len: usize) -> Option<usize> {
+        let target = jump.target();
+        match self {
+            Boundary::Wall => usize::try_from(target).ok().filter(|&index| index < len),
+            Boundary::Wrap if len == 0 => None,
+            Boundary::Wrap => Some(target.rem_euclid(len as i128) as usize),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Boundary::Wall, 0, 5, Some(0))]
+    #[test_case(Boundary::Wall, 4, 5, Some(4))]
+    #[test_case(Boundary::Wall, 5, 5, None)]
+    #[test_case(Boundary::Wall, -1, 5, None)]
+    #[test_case(Boundary::Wrap, 4, 5, Some(4))]
+    #[test_case(Boundary::Wrap, 5, 5, Some(0))]
+    #[test_case(Boundary::Wrap, 12, 5, Some(2))]
+    #[test_case(Boundary::Wrap, -1, 5, Some(4))]
+    #[test_case(Boundary::Wrap, -11, 5, Some(4))]
+    #[test_case(Boundary::Wrap, 0, 0, None)]
+    fn resolve_cases(boundary: Boundary, target: isize, len: usize, expected: Option<usize>) {
+        let jump = match usize::try_from(target) {
+            Ok(index) => Jump::to(index),
+            Err(_) => Jump::left(0, target.unsigned_abs()),
+        };
+        assert_eq!(boundary.resolve(jump, len), expected);
+    }
+
+    // usize::MAX is odd, and a multiple of 5
+    #[test_case(Boundary::Wall, Jump::right(0, usize::MAX), 2, None)]
+    #[test_case(Boundary::Wall, Jump::left(1, usize::MAX), 2, None)]
+    #[test_case(Boundary::Wrap, Jump::right(0, usize::MAX), 2, Some(1))]
+    #[test_case(Boundary::Wrap, Jump::left(1, usize::MAX), 2, Some(0))]
+    #[test_case(Boundary::Wrap, Jump::right(3, usize::MAX), 5, Some(3))]
+    #[test_case(Boundary::Wrap, Jump::left(3, usize::MAX), 5, Some(3))]
+    #[test_case(Boundary::Wrap, Jump::right(usize::MAX - 1, usize::MAX), 5, Some(4))]
+    fn long_jumps_wrap_exactly(
+        boundary: Boundary,
+        jump: Jump,
+        len: usize,
+        expected: Option<usize>,
+    ) {
+        assert_eq!(boundary.resolve(jump, len), expected);
+    }
+}
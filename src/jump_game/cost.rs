@@ -0,0 +1,189 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use super::game_ref::{build_path, Landing};
+use super::{BoardValue, JumpGame, JumpGameRef, JumpRule};
+
+/// # The cheapest way to win a JumpGame, as found by [`JumpGame::cheapest_path`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CheapestPath {
+    /// The total cost of every jump along the path.
+    pub cost: u64,
+    /// The indices visited, from the starting index to the goal.
+    pub path: Vec<usize>,
+}
+
+impl<R: JumpRule> JumpGame<R> {
+    /// # Finds the path with the lowest total cost from the starting index to a goal.
+    ///
+    /// `cost` is called with the index jumped from, the index jumped to, and the value of the
+    /// cell jumped from, and returns the cost of that single jump. When the goal is to leave the
+    /// board the final jump lands on an index past the end of the board, and the path ends on the
+    /// cell that jumps off.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::{ForwardUpTo, JumpGame};
+    /// let game = JumpGame::new(vec![4, 1, 1, 1, 0], 0).with_rule(ForwardUpTo);
+    /// assert_eq!(game.shortest_path(), Some(vec![0, 4]));
+    ///
+    /// // longer jumps cost much more energy
+    /// let cheapest = game
+    ///     .cheapest_path(|from, to, _| (from.abs_diff(to) as u64).pow(2))
+    ///     .unwrap();
+    /// assert_eq!(cheapest.cost, 4);
+    /// assert_eq!(cheapest.path, vec![0, 1, 2, 3, 4]);
+    /// ```
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert_eq!(game.cheapest_path(|_, _, _| 1), None);
+    /// ```
+    pub fn cheapest_path<C>(&self, cost: C) -> Option<CheapestPath>
+    where
+        C: Fn(usize, usize, usize) -> u64,
+    {
+        self.view().cheapest_path(cost)
+    }
+}
+
+impl<T: BoardValue, R: JumpRule> JumpGameRef<'_, T, R> {
+    /// # Finds the path with the lowest total cost to a goal, see
+    /// [`JumpGame::cheapest_path`].
+    pub fn cheapest_path<C>(&self, cost: C) -> Option<CheapestPath>
+    where
+        C: Fn(usize, usize, usize) -> u64,
+    {
+        // the extra index at the end of the board stands in for jumping off it to win
+        let exit = self.board.len();
+        let mut costs = HashMap::<usize, u64>::new();
+        let mut parents = HashMap::<usize, usize>::new();
+        let mut heap = BinaryHeap::<Reverse<(u64, usize)>>::new();
+
+        costs.insert(self.starting_index, 0);
+        heap.push(Reverse((0, self.starting_index)));
+
+        while let Some(Reverse((current_cost, current_index))) = heap.pop() {
+            if costs
+                .get(&current_index)
+                .is_some_and(|&best| best < current_cost)
+            {
+                // a cheaper way here has already been handled
+                continue;
+            }
+            if current_index == exit {
+                let end = parents[&exit];
+                return Some(CheapestPath {
+                    cost: current_cost,
+                    path: build_path(&parents, end),
+                });
+            }
+
+            let value = self.value(current_index);
+            if self.is_goal_cell(current_index) {
+                return Some(CheapestPath {
+                    cost: current_cost,
+                    path: build_path(&parents, current_index),
+                });
+            }
+
+            self.rule.targets(
+                current_index,
+                self.jump_length(current_index),
+                self.board.len(),
+                |jump| {
+                    let next_index = match self.land(jump) {
+                        Landing::Cell(next_index) => next_index,
+                        Landing::Exit => exit,
+                        Landing::Off => return,
+                    };
+                    let jump_to = if next_index == exit {
+                        usize::try_from(jump.target()).unwrap_or(usize::MAX)
+                    } else {
+                        next_index
+                    };
+                    let next_cost =
+                        current_cost.saturating_add(cost(current_index, jump_to, value));
+                    if costs.get(&next_index).is_none_or(|&best| next_cost < best) {
+                        costs.insert(next_index, next_cost);
+                        parents.insert(next_index, current_index);
+                        heap.push(Reverse((next_cost, next_index)));
+                    }
+                },
+            );
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{BidirectionalUpTo, ForwardUpTo, Goal};
+    use test_case::test_case;
+
+    fn squared_distance(from: usize, to: usize, _value: usize) -> u64 {
+        (from.abs_diff(to) as u64).pow(2)
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0)]
+    #[test_case(vec![1, 7, 3, 0, 3, 2], 5)]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2], 5)]
+    #[test_case(vec![1, 1, 1, 1, 0], 0)]
+    fn unit_costs_match_the_fewest_jumps(board: Vec<usize>, starting_index: usize) {
+        let game = JumpGame::new(board, starting_index);
+        let cheapest = game.cheapest_path(|_, _, _| 1).unwrap();
+        assert_eq!(Some(cheapest.cost as usize), game.min_jumps());
+        assert_eq!(cheapest.path.len() - 1, cheapest.cost as usize);
+    }
+
+    #[test]
+    fn cheapest_path_can_take_more_jumps_than_the_shortest_path() {
+        let game = JumpGame::new(vec![0, 3, 1, 3, 1, 2], 5).with_rule(BidirectionalUpTo);
+        assert_eq!(game.shortest_path(), Some(vec![5, 3, 0]));
+
+        let cheapest = game.cheapest_path(squared_distance).unwrap();
+        assert_eq!(cheapest.path, vec![5, 4, 3, 2, 1, 0]);
+        assert_eq!(cheapest.cost, 5);
+    }
+
+    #[test]
+    fn per_cell_costs_avoid_expensive_cells() {
+        // landing on index 1 is expensive, everything else is cheap
+        let cell_costs = [0, 10, 1, 1, 1];
+        let game = JumpGame::new(vec![2, 3, 1, 1, 0], 0).with_rule(ForwardUpTo);
+        assert_eq!(game.shortest_path(), Some(vec![0, 1, 4]));
+
+        let cheapest = game.cheapest_path(|_, to, _| cell_costs[to]).unwrap();
+        assert_eq!(cheapest.path, vec![0, 2, 3, 4]);
+        assert_eq!(cheapest.cost, 3);
+    }
+
+    #[test]
+    fn exit_jumps_are_charged_to_the_index_past_the_end() {
+        let game = JumpGame::with_goal(vec![1, 3, 1], 0, Goal::ExitRight);
+        let cheapest = game.cheapest_path(|_, to, _| to as u64).unwrap();
+        assert_eq!(cheapest.path, vec![0, 1]);
+        assert_eq!(cheapest.cost, 1 + 4);
+    }
+
+    #[test]
+    fn starting_on_a_goal_costs_nothing() {
+        let game = JumpGame::new(vec![2, 0, 1], 1);
+        assert_eq!(
+            game.cheapest_path(|_, _, _| 1),
+            Some(CheapestPath {
+                cost: 0,
+                path: vec![1]
+            })
+        );
+    }
+
+    #[test]
+    fn unwinnable_boards_have_no_cheapest_path() {
+        let game = JumpGame::new(vec![1, 7, 3, 0, 3, 2], 0);
+        assert_eq!(game.cheapest_path(squared_distance), None);
+    }
+}
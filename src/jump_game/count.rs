@@ -0,0 +1,317 @@
+use std::error::Error;
+use std::fmt;
+
+use super::game_ref::Landing;
+use super::{BoardValue, JumpGame, JumpGameRef, JumpRule};
+
+/// # The reasons counting winning paths can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathCountError {
+    /// There are more winning paths with this many jumps than fit in a `u128`.
+    Overflow { jumps: usize },
+    /// Counting simple paths would need to visit more cells than the limit allows.
+    NodeLimit { limit: usize },
+    /// There is not enough memory to hold a count for every number of jumps up to this many.
+    TooManyJumps { max_jumps: usize },
+}
+
+impl fmt::Display for PathCountError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathCountError::Overflow { jumps } => {
+                write!(
+                    f,
+                    "Number of paths with {jumps} jumps does not fit in a u128"
+                )
+            }
+            PathCountError::NodeLimit { limit } => {
+                write!(
+                    f,
+                    "Counting simple paths must visit no more than {limit} cells"
+                )
+            }
+            PathCountError::TooManyJumps { max_jumps } => {
+                write!(
+                    f,
+                    "Can not hold the path counts for up to {max_jumps} jumps"
+                )
+            }
+        }
+    }
+}
+
+impl Error for PathCountError {}
+
+impl<R: JumpRule> JumpGame<R> {
+    /// # Counts the winning paths with each number of jumps, from 0 up to `max_jumps`.
+    ///
+    /// A winning path ends the first time it lands on a goal or leaves the board to win, and
+    /// may visit the same cell more than once on the way. Paths are only the same if they visit
+    /// the same cells in the same order, and summing the counts gives the number of winning
+    /// paths with at most `max_jumps` jumps.
+    ///
+    /// This works forward one jump at a time, counting the paths that end on each cell, so it
+    /// takes O(`max_jumps` × the number of jumps on the board) time. It fails with
+    /// [`PathCountError::TooManyJumps`] rather than panicking if the counts can't be allocated.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// // the only way to win is to walk right, but every extra step back left is a new path
+    /// let game = JumpGame::new(vec![1, 1, 1, 0], 0);
+    /// assert_eq!(game.count_paths_by_jumps(7), Ok(vec![0, 0, 0, 1, 0, 2, 0, 4]));
+    /// ```
+    pub fn count_paths_by_jumps(&self, max_jumps: usize) -> Result<Vec<u128>, PathCountError> {
+        self.view().count_paths_by_jumps(max_jumps)
+    }
+
+    /// # Counts the winning paths that never visit the same cell twice.
+    ///
+    /// Cycles make the number of winning paths infinite, but there are only finitely many that
+    /// never repeat a cell. There can still be exponentially many, so they are counted by a
+    /// depth first search that stops with [`PathCountError::NodeLimit`] if it visits more than
+    /// `node_limit` cells.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{BidirectionalUpTo, JumpGame, PathCountError};
+    /// let game = JumpGame::new(vec![1, 1, 1, 0], 0);
+    /// assert_eq!(game.count_simple_paths(100), Ok(1));
+    ///
+    /// let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4).with_rule(BidirectionalUpTo);
+    /// assert_eq!(game.count_simple_paths(100), Ok(9));
+    /// assert_eq!(game.count_simple_paths(10), Err(PathCountError::NodeLimit { limit: 10 }));
+    /// ```
+    pub fn count_simple_paths(&self, node_limit: usize) -> Result<u128, PathCountError> {
+        self.view().count_simple_paths(node_limit)
+    }
+}
+
+impl<T: BoardValue, R: JumpRule> JumpGameRef<'_, T, R> {
+    /// # Counts the winning paths with each number of jumps, see
+    /// [`JumpGame::count_paths_by_jumps`].
+    pub fn count_paths_by_jumps(&self, max_jumps: usize) -> Result<Vec<u128>, PathCountError> {
+        let too_many_jumps = PathCountError::TooManyJumps { max_jumps };
+        let len = max_jumps
+            .checked_add(1)
+            .ok_or_else(|| too_many_jumps.clone())?;
+        let mut counts = Vec::new();
+        counts.try_reserve_exact(len).map_err(|_| too_many_jumps)?;
+        counts.resize(len, 0);
+        if self.is_goal_cell(self.starting_index) {
+            counts[0] = 1;
+            return Ok(counts);
+        }
+
+        let moves = self.moves();
+        // the number of paths that end on each cell without having won yet
+        let mut paths = vec![0u128; self.board.len()];
+        paths[self.starting_index] = 1;
+        for (jumps, count) in counts.iter_mut().enumerate().skip(1) {
+            if paths.iter().all(|&ending_here| ending_here == 0) {
+                // every path has already won or got stuck, so the rest of the counts are 0
+                break;
+            }
+            let overflow = PathCountError::Overflow { jumps };
+            let mut next = vec![0u128; self.board.len()];
+            for (index, &ending_here) in paths.iter().enumerate() {
+                if ending_here == 0 {
+                    continue;
+                }
+                let (cells, exits) = &moves[index];
+                if *exits {
+                    *count = count
+                        .checked_add(ending_here)
+                        .ok_or_else(|| overflow.clone())?;
+                }
+                for &cell in cells {
+                    let total = if self.is_goal_cell(cell) {
+                        &mut *count
+                    } else {
+                        &mut next[cell]
+                    };
+                    *total = total
+                        .checked_add(ending_here)
+                        .ok_or_else(|| overflow.clone())?;
+                }
+            }
+            paths = next;
+        }
+        Ok(counts)
+    }
+
+    /// # Counts the winning paths that never visit the same cell twice, see
+    /// [`JumpGame::count_simple_paths`].
+    pub fn count_simple_paths(&self, node_limit: usize) -> Result<u128, PathCountError> {
+        let limit_reached = PathCountError::NodeLimit { limit: node_limit };
+        if node_limit == 0 {
+            return Err(limit_reached);
+        }
+        if self.is_goal_cell(self.starting_index) {
+            return Ok(1);
+        }
+
+        let moves = self.moves();
+        let mut on_path = vec![false; self.board.len()];
+        let mut visits = 1;
+        let mut count = 0u128;
+        // each entry is a cell on the current path and how many of its moves have been tried
+        let mut stack = vec![(self.starting_index, 0)];
+        on_path[self.starting_index] = true;
+        count += u128::from(moves[self.starting_index].1);
+
+        while let Some((index, tried)) = stack.pop() {
+            let Some(&next) = moves[index].0.get(tried) else {
+                on_path[index] = false;
+                continue;
+            };
+            stack.push((index, tried + 1));
+            if on_path[next] {
+                continue;
+            }
+            visits += 1;
+            if visits > node_limit {
+                return Err(limit_reached);
+            }
+            if self.is_goal_cell(next) {
+                count += 1;
+                continue;
+            }
+            count += u128::from(moves[next].1);
+            on_path[next] = true;
+            stack.push((next, 0));
+        }
+        Ok(count)
+    }
+
+    /// The distinct cells each index can jump to, and whether it can win by leaving the board.
+    ///
+    /// Different jumps that land on the same cell make the same path, so they only count once.
+    fn moves(&self) -> Vec<(Vec<usize>, bool)> {
+        (0..self.board.len())
+            .map(|index| {
+                let mut cells = Vec::new();
+                let mut exits = false;
+                self.rule
+                    .targets(
+                        index,
+                        self.jump_length(index),
+                        self.board.len(),
+                        |jump| match self.land(jump) {
+                            Landing::Cell(next) => cells.push(next),
+                            Landing::Exit => exits = true,
+                            Landing::Off => {}
+                        },
+                    );
+                cells.sort_unstable();
+                cells.dedup();
+                (cells, exits)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{BidirectionalUpTo, Boundary, ForwardUpTo, Goal};
+    use test_case::test_case;
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, 4, vec![0, 0, 1, 0, 0])]
+    #[test_case(vec![2, 3, 1, 0, 1], 2, 6, vec![0, 1, 0, 1, 0, 0, 0])]
+    #[test_case(vec![0, 1], 0, 2, vec![1, 0, 0])]
+    #[test_case(vec![2, 1, 2, 0], 0, 5, vec![0; 6])]
+    #[test_case(vec![1, 1, 1, 0], 0, 7, vec![0, 0, 0, 1, 0, 2, 0, 4])]
+    fn counts_paths_by_jumps(
+        board: Vec<usize>,
+        start: usize,
+        max_jumps: usize,
+        expected: Vec<u128>,
+    ) {
+        let game = JumpGame::new(board, start);
+        assert_eq!(game.count_paths_by_jumps(max_jumps), Ok(expected));
+    }
+
+    #[test_case(usize::MAX)]
+    #[test_case(usize::MAX - 1)]
+    #[test_case(usize::MAX / 2)]
+    fn too_many_jumps_are_an_error(max_jumps: usize) {
+        let game = JumpGame::new(vec![1, 1, 1, 0], 0);
+        assert_eq!(
+            game.count_paths_by_jumps(max_jumps),
+            Err(PathCountError::TooManyJumps { max_jumps })
+        );
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, 1)]
+    #[test_case(vec![2, 3, 1, 0, 1], 2, 2)]
+    #[test_case(vec![0, 1], 0, 1)]
+    #[test_case(vec![2, 1, 2, 0], 0, 0)]
+    #[test_case(vec![1, 1, 1, 0], 0, 1)]
+    #[test_case(vec![1, 1, 1, 0, 1, 1, 1], 3, 1)]
+    fn counts_simple_paths(board: Vec<usize>, start: usize, expected: u128) {
+        let game = JumpGame::new(board, start);
+        assert_eq!(game.count_simple_paths(1_000), Ok(expected));
+    }
+
+    #[test]
+    fn rules_boundaries_and_goals_apply() {
+        // 0 -> 2, 0 -> 1 -> 2 and 0 -> 1 -> 3 -> 2, or 0 -> 1 -> 0 -> 2 going back to 0
+        let game = JumpGame::new(vec![2, 2, 0, 1], 0).with_rule(BidirectionalUpTo);
+        assert_eq!(game.count_simple_paths(100), Ok(3));
+        assert_eq!(game.count_paths_by_jumps(3), Ok(vec![0, 1, 1, 2]));
+
+        let game = JumpGame::new(vec![2, 2, 0, 1], 0).with_rule(ForwardUpTo);
+        assert_eq!(game.count_simple_paths(100), Ok(2));
+        assert_eq!(game.count_paths_by_jumps(3), Ok(vec![0, 1, 1, 0]));
+
+        let game = JumpGame::with_goal(vec![2, 1, 1], 0, Goal::ExitRight);
+        assert_eq!(game.count_simple_paths(100), Ok(1));
+        assert_eq!(game.count_paths_by_jumps(4), Ok(vec![0, 0, 1, 0, 1]));
+
+        // the jumps from 1 wrap around to the goal at 2, and to 0 which can jump on to it
+        let game = JumpGame::new(vec![1, 2, 0], 1).with_boundary(Boundary::Wrap);
+        assert_eq!(game.count_paths_by_jumps(2), Ok(vec![0, 1, 1]));
+        assert_eq!(game.count_simple_paths(100), Ok(2));
+    }
+
+    #[test]
+    fn jumps_landing_on_the_same_cell_count_once() {
+        // on a ring of 4, jumping 2 either way from 0 lands on 2
+        let game = JumpGame::new(vec![2, 1, 0, 1], 0).with_boundary(Boundary::Wrap);
+        assert_eq!(game.count_paths_by_jumps(1), Ok(vec![0, 1]));
+        assert_eq!(game.count_simple_paths(100), Ok(1));
+    }
+
+    #[test]
+    fn reports_overflow() {
+        // every cell can reach every other one, so the number of paths grows exponentially
+        let mut board = vec![40; 40];
+        board[39] = 0;
+        let game = JumpGame::new(board, 0).with_rule(BidirectionalUpTo);
+        let error = game.count_paths_by_jumps(100).unwrap_err();
+        assert!(matches!(error, PathCountError::Overflow { jumps } if jumps < 100));
+    }
+
+    #[test]
+    fn reports_the_node_limit() {
+        let game = JumpGame::new(vec![2, 2, 0, 1], 0).with_rule(BidirectionalUpTo);
+        assert_eq!(game.count_simple_paths(6), Ok(3));
+        assert_eq!(
+            game.count_simple_paths(5),
+            Err(PathCountError::NodeLimit { limit: 5 })
+        );
+        assert_eq!(
+            game.count_simple_paths(0),
+            Err(PathCountError::NodeLimit { limit: 0 })
+        );
+    }
+
+    #[test]
+    fn borrowed_boards_can_be_counted() {
+        let board: [u8; 4] = [1, 1, 1, 0];
+        let game = JumpGameRef::new(&board, 0);
+        assert_eq!(game.count_paths_by_jumps(5), Ok(vec![0, 0, 0, 1, 0, 2]));
+        assert_eq!(game.count_simple_paths(10), Ok(1));
+    }
+}
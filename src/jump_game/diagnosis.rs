@@ -0,0 +1,371 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use super::game_ref::Landing;
+use super::{BoardValue, Goal, JumpGame, JumpGameRef, JumpRule};
+
+/// # Everything the player can get up to from the starting index, as found by
+/// [`JumpGame::diagnose`].
+///
+/// Its [`Display`](fmt::Display) explains in plain words why the game can or can't be won.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnosis {
+    starting_index: usize,
+    winnable: bool,
+    reachable: BTreeSet<usize>,
+    off_board: Vec<(usize, i128)>,
+    cycles: Vec<BTreeSet<usize>>,
+    unreachable_goals: BTreeSet<usize>,
+    goal: GoalKind,
+}
+
+/// Just enough of the goal to describe it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GoalKind {
+    Zero,
+    Index,
+    Value(usize),
+    ExitRight,
+    Custom,
+}
+
+impl Diagnosis {
+    /// # Whether a goal can be reached from the starting index.
+    pub fn is_winnable(&self) -> bool {
+        self.winnable
+    }
+
+    /// # Every index the player can land on from the starting index, including it.
+    pub fn reachable(&self) -> &BTreeSet<usize> {
+        &self.reachable
+    }
+
+    /// # Every jump from a reachable index that would leave the board without winning, as
+    /// `(from, target)` pairs, see [`Jump::target`](super::Jump::target).
+    pub fn off_board_moves(&self) -> &[(usize, i128)] {
+        &self.off_board
+    }
+
+    /// # The groups of reachable indices the player can jump around forever, smallest index
+    /// first.
+    ///
+    /// Every index in a group can reach every other one, so once the game is unwinnable these
+    /// are the loops the player is trapped in.
+    pub fn cycles(&self) -> &[BTreeSet<usize>] {
+        &self.cycles
+    }
+
+    /// # The goal cells that can't be reached from the starting index.
+    ///
+    /// Always empty when the goal is to leave the board.
+    pub fn unreachable_goals(&self) -> &BTreeSet<usize> {
+        &self.unreachable_goals
+    }
+}
+
+impl fmt::Display for Diagnosis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "from index {} you can reach {:?}",
+            self.starting_index, self.reachable
+        )?;
+        if self.winnable {
+            return write!(f, ", which wins the game");
+        }
+
+        if self.off_board.is_empty() {
+            write!(f, "; all moves from these return to the set")?;
+        } else {
+            write!(
+                f,
+                "; all moves from these either exit the board or return to the set"
+            )?;
+        }
+        if !self.cycles.is_empty() {
+            let cycles: Vec<String> = self
+                .cycles
+                .iter()
+                .map(|cycle| format!("{cycle:?}"))
+                .collect();
+            write!(
+                f,
+                "; you are trapped jumping around {}",
+                cycles.join(" and ")
+            )?;
+        }
+
+        let goals = &self.unreachable_goals;
+        match &self.goal {
+            GoalKind::Zero => write!(f, "; zeros at {goals:?} are unreachable"),
+            GoalKind::Index => write!(f, "; goal indices {goals:?} are unreachable"),
+            GoalKind::Value(value) => write!(f, "; {value}s at {goals:?} are unreachable"),
+            GoalKind::ExitRight => write!(f, "; no move leaves the board to the right"),
+            GoalKind::Custom => write!(f, "; goal cells at {goals:?} are unreachable"),
+        }
+    }
+}
+
+impl<R: JumpRule> JumpGame<R> {
+    /// # Works out where the player can get to from the starting index, to explain why the
+    /// game can or can't be won.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game = JumpGame::new(vec![2, 1, 2, 0], 0);
+    /// assert!(!game.is_winnable());
+    ///
+    /// let diagnosis = game.diagnose();
+    /// assert_eq!(diagnosis.off_board_moves(), [(0, -2), (2, 4)]);
+    /// assert_eq!(
+    ///     diagnosis.to_string(),
+    ///     "from index 0 you can reach {0, 2}; \
+    ///      all moves from these either exit the board or return to the set; \
+    ///      you are trapped jumping around {0, 2}; \
+    ///      zeros at {3} are unreachable"
+    /// );
+    /// ```
+    pub fn diagnose(&self) -> Diagnosis {
+        self.view().diagnose()
+    }
+}
+
+impl<T: BoardValue, R: JumpRule> JumpGameRef<'_, T, R> {
+    /// # Works out where the player can get to from the starting index, see
+    /// [`JumpGame::diagnose`].
+    pub fn diagnose(&self) -> Diagnosis {
+        let len = self.board.len();
+        let mut targets = vec![Vec::new(); len];
+        let mut reachable = BTreeSet::new();
+        let mut off_board = Vec::new();
+        let mut winnable = false;
+
+        // the same depth first search as is_winnable, but it keeps going after finding a goal
+        // and remembers every jump it makes
+        let mut stack = vec![self.starting_index];
+        reachable.insert(self.starting_index);
+        while let Some(index) = stack.pop() {
+            if self.is_goal_cell(index) {
+                // the game ends on a goal, so there are no jumps out of it
+                winnable = true;
+                continue;
+            }
+            self.rule
+                .targets(index, self.jump_length(index), len, |jump| {
+                    match self.land(jump) {
+                        Landing::Cell(next) => {
+                            targets[index].push(next);
+                            if reachable.insert(next) {
+                                stack.push(next);
+                            }
+                        }
+                        Landing::Exit => winnable = true,
+                        Landing::Off => off_board.push((index, jump.target())),
+                    }
+                });
+        }
+        off_board.sort_unstable();
+
+        let goal_cells = (0..len).filter(|&index| self.is_goal_cell(index));
+        let unreachable_goals = goal_cells
+            .filter(|index| !reachable.contains(index))
+            .collect();
+        let goal = match self.goal.as_ref() {
+            Goal::ZeroCell => GoalKind::Zero,
+            Goal::Index(_) => GoalKind::Index,
+            Goal::Value(value) => GoalKind::Value(*value),
+            Goal::ExitRight => GoalKind::ExitRight,
+            Goal::Custom(_) => GoalKind::Custom,
+        };
+
+        Diagnosis {
+            starting_index: self.starting_index,
+            winnable,
+            cycles: cycles(&targets, &reachable),
+            reachable,
+            off_board,
+            unreachable_goals,
+            goal,
+        }
+    }
+}
+
+/// Finds the strongly connected components that contain a cycle, using Kosaraju's algorithm.
+fn cycles(targets: &[Vec<usize>], reachable: &BTreeSet<usize>) -> Vec<BTreeSet<usize>> {
+    // first pass: order the cells by when a depth first search finishes with them
+    let mut visited = vec![false; targets.len()];
+    let mut finished = Vec::with_capacity(reachable.len());
+    for &root in reachable {
+        if visited[root] {
+            continue;
+        }
+        visited[root] = true;
+        let mut stack = vec![(root, 0)];
+        while let Some((index, next)) = stack.pop() {
+            match targets[index].get(next) {
+                Some(&target) => {
+                    stack.push((index, next + 1));
+                    if !visited[target] {
+                        visited[target] = true;
+                        stack.push((target, 0));
+                    }
+                }
+                None => finished.push(index),
+            }
+        }
+    }
+
+    // second pass: walk the reversed jumps, latest finisher first, to collect each component
+    let mut sources = vec![Vec::new(); targets.len()];
+    for (index, index_targets) in targets.iter().enumerate() {
+        for &target in index_targets {
+            sources[target].push(index);
+        }
+    }
+    let mut assigned = vec![false; targets.len()];
+    let mut components = Vec::new();
+    for &root in finished.iter().rev() {
+        if assigned[root] {
+            continue;
+        }
+        assigned[root] = true;
+        let mut component = BTreeSet::from([root]);
+        let mut stack = vec![root];
+        while let Some(index) = stack.pop() {
+            for &source in &sources[index] {
+                if !assigned[source] {
+                    assigned[source] = true;
+                    component.insert(source);
+                    stack.push(source);
+                }
+            }
+        }
+
+        let loops = component.len() > 1 || targets[root].contains(&root);
+        if loops {
+            components.push(component);
+        }
+    }
+
+    components.sort_unstable_by_key(|component| component.first().copied());
+    components
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{Boundary, ForwardUpTo};
+    use test_case::test_case;
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, true, &[0, 1, 3])]
+    #[test_case(vec![1, 2, 0, 3, 2], 0, false, &[0, 1, 3])]
+    #[test_case(vec![2, 1, 2, 0], 0, false, &[0, 2])]
+    #[test_case(vec![3, 0, 2, 3, 0, 1], 2, true, &[0, 2, 3, 4])]
+    fn finds_what_is_reachable(
+        board: Vec<usize>,
+        start: usize,
+        winnable: bool,
+        reachable: &[usize],
+    ) {
+        let game = JumpGame::new(board, start);
+        let diagnosis = game.diagnose();
+        assert_eq!(diagnosis.is_winnable(), winnable);
+        assert_eq!(diagnosis.is_winnable(), game.is_winnable());
+        assert_eq!(diagnosis.reachable(), &reachable.iter().copied().collect());
+    }
+
+    #[test]
+    fn explains_unwinnable_boards() {
+        let diagnosis = JumpGame::new(vec![1, 2, 0, 3, 2], 0).diagnose();
+        assert_eq!(diagnosis.off_board_moves(), [(0, -1), (1, -1), (3, 6)]);
+        assert_eq!(diagnosis.cycles(), [BTreeSet::from([0, 1, 3])]);
+        assert_eq!(diagnosis.unreachable_goals(), &BTreeSet::from([2]));
+        assert_eq!(
+            diagnosis.to_string(),
+            "from index 0 you can reach {0, 1, 3}; \
+             all moves from these either exit the board or return to the set; \
+             you are trapped jumping around {0, 1, 3}; \
+             zeros at {2} are unreachable"
+        );
+    }
+
+    #[test]
+    fn explains_winnable_boards() {
+        let diagnosis = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4).diagnose();
+        assert_eq!(
+            diagnosis.to_string(),
+            "from index 4 you can reach {1, 3, 4}, which wins the game"
+        );
+    }
+
+    #[test]
+    fn finds_separate_cycles() {
+        // 4 jumps into 0 <-> 1, which can drop into 2 <-> 5 but never get back
+        let diagnosis = JumpGame::new(vec![1, 1, 3, 0, 3, 3], 4).diagnose();
+        assert!(!diagnosis.is_winnable());
+        assert_eq!(
+            diagnosis.cycles(),
+            [BTreeSet::from([0, 1]), BTreeSet::from([2, 5])]
+        );
+        assert!(diagnosis
+            .to_string()
+            .contains("you are trapped jumping around {0, 1} and {2, 5}"));
+    }
+
+    #[test]
+    fn rules_and_boundaries_apply() {
+        let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0).with_boundary(Boundary::Wrap);
+        let diagnosis = game.diagnose();
+        assert!(diagnosis.is_winnable());
+        assert!(diagnosis.off_board_moves().is_empty());
+
+        let game = JumpGame::new(vec![3, 1, 2, 5, 0], 0);
+        assert!(!game.diagnose().is_winnable());
+        assert!(game.with_rule(ForwardUpTo).diagnose().is_winnable());
+    }
+
+    #[test]
+    fn describes_each_goal() {
+        let game = JumpGame::with_goal(vec![2, 1, 2, 1], 0, Goal::Value(1));
+        assert!(game
+            .diagnose()
+            .to_string()
+            .ends_with("1s at {1, 3} are unreachable"));
+
+        let game = JumpGame::with_goal(vec![1, 1, 0], 0, Goal::ExitRight);
+        let diagnosis = game.diagnose();
+        assert_eq!(diagnosis.off_board_moves(), [(0, -1)]);
+        assert_eq!(
+            diagnosis.to_string(),
+            "from index 0 you can reach {0, 1, 2}; \
+             all moves from these either exit the board or return to the set; \
+             you are trapped jumping around {0, 1} and {2}; \
+             no move leaves the board to the right"
+        );
+
+        let game = JumpGame::with_goal(vec![2, 0, 2, 0], 0, Goal::Index(BTreeSet::from([3])));
+        assert!(game
+            .diagnose()
+            .to_string()
+            .ends_with("goal indices {3} are unreachable"));
+    }
+
+    #[test]
+    fn goals_end_the_game() {
+        // 2 can only be reached by jumping on from the goal at 1
+        let game = JumpGame::with_goal(vec![1, 1, 5, 1], 0, Goal::Index(BTreeSet::from([1])));
+        let diagnosis = game.diagnose();
+        assert!(diagnosis.is_winnable());
+        assert_eq!(diagnosis.reachable(), &BTreeSet::from([0, 1]));
+        assert_eq!(diagnosis.off_board_moves(), [(0, -1)]);
+        assert!(diagnosis.cycles().is_empty());
+    }
+
+    #[test]
+    fn borrowed_boards_can_be_diagnosed() {
+        let board: [u8; 4] = [2, 1, 2, 0];
+        let diagnosis = JumpGameRef::new(&board, 0).diagnose();
+        assert_eq!(diagnosis, JumpGame::new(vec![2, 1, 2, 0], 0).diagnose());
+    }
+}
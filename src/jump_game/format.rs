@@ -0,0 +1,709 @@
+//! A small text format for writing jump game puzzles by hand.
+//!
+//! Each puzzle is a list of `key: value` lines, and a file can hold several puzzles separated by
+//! `---` lines. Blank lines and lines starting with `#` are ignored.
+//!
+//! ```text
+//! # lines starting with a hash are comments
+//! name: Warm up
+//! board: 1 2 3 0 3 2
+//! start: 0
+//! expect: 2
+//! ---
+//! name: Round the bend
+//! rule: forward
+//! board: 2 3 1 1 4
+//! start: 3
+//! boundary: wrap
+//! goal: index 0
+//! expect: winnable
+//! ```
+//!
+//! `board` and `start` are required. The optional keys are
+//! - `name`: any text
+//! - `rule`: `symmetric` (default), `forward` or `bidirectional`
+//! - `boundary`: `wall` (default) or `wrap`
+//! - `goal`: `zero` (default), `exit-right`, `index <indices...>` or `value <value>`
+//! - `expect`: `winnable`, `unwinnable` or the fewest number of jumps needed to win
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::{
+    BidirectionalUpTo, Boundary, ForwardUpTo, Goal, Jump, JumpGame, JumpGameError, JumpRule,
+    SymmetricExact,
+};
+
+/// # One of the built-in jump rules, picked at runtime.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum RuleKind {
+    /// See [`SymmetricExact`].
+    #[default]
+    Symmetric,
+    /// See [`ForwardUpTo`].
+    Forward,
+    /// See [`BidirectionalUpTo`].
+    Bidirectional,
+}
+
+impl JumpRule for RuleKind {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, len: usize, visit: F) {
+        match self {
+            RuleKind::Symmetric => SymmetricExact.targets(index, value, len, visit),
+            RuleKind::Forward => ForwardUpTo.targets(index, value, len, visit),
+            RuleKind::Bidirectional => BidirectionalUpTo.targets(index, value, len, visit),
+        }
+    }
+}
+
+impl From<SymmetricExact> for RuleKind {
+    fn from(_: SymmetricExact) -> Self {
+        RuleKind::Symmetric
+    }
+}
+
+impl From<ForwardUpTo> for RuleKind {
+    fn from(_: ForwardUpTo) -> Self {
+        RuleKind::Forward
+    }
+}
+
+impl From<BidirectionalUpTo> for RuleKind {
+    fn from(_: BidirectionalUpTo) -> Self {
+        RuleKind::Bidirectional
+    }
+}
+
+impl TryFrom<RuleKind> for SymmetricExact {
+    type Error = RuleKind;
+
+    fn try_from(rule: RuleKind) -> Result<Self, Self::Error> {
+        match rule {
+            RuleKind::Symmetric => Ok(SymmetricExact),
+            other => Err(other),
+        }
+    }
+}
+
+impl TryFrom<RuleKind> for ForwardUpTo {
+    type Error = RuleKind;
+
+    fn try_from(rule: RuleKind) -> Result<Self, Self::Error> {
+        match rule {
+            RuleKind::Forward => Ok(ForwardUpTo),
+            other => Err(other),
+        }
+    }
+}
+
+impl TryFrom<RuleKind> for BidirectionalUpTo {
+    type Error = RuleKind;
+
+    fn try_from(rule: RuleKind) -> Result<Self, Self::Error> {
+        match rule {
+            RuleKind::Bidirectional => Ok(BidirectionalUpTo),
+            other => Err(other),
+        }
+    }
+}
+
+impl fmt::Display for RuleKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RuleKind::Symmetric => write!(f, "symmetric"),
+            RuleKind::Forward => write!(f, "forward"),
+            RuleKind::Bidirectional => write!(f, "bidirectional"),
+        }
+    }
+}
+
+/// # The answer a puzzle is expected to have.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Expectation {
+    Winnable,
+    Unwinnable,
+    /// Winnable, and the shortest path takes exactly this many jumps.
+    MinJumps(usize),
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expectation::Winnable => write!(f, "winnable"),
+            Expectation::Unwinnable => write!(f, "unwinnable"),
+            Expectation::MinJumps(jumps) => write!(f, "{jumps}"),
+        }
+    }
+}
+
+/// # A JumpGame along with the metadata that describes it.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Puzzle {
+    pub name: Option<String>,
+    pub expected: Option<Expectation>,
+    pub game: JumpGame<RuleKind>,
+}
+
+impl Puzzle {
+    /// # Checks the game against the expected answer, if there is one.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::Puzzle;
+    /// let puzzle: Puzzle = "board: 1 2 3 0 3 2\nstart: 0\nexpect: 2".parse().unwrap();
+    /// assert_eq!(puzzle.meets_expectation(), Some(true));
+    /// ```
+    pub fn meets_expectation(&self) -> Option<bool> {
+        self.expected.map(|expected| match expected {
+            Expectation::Winnable => self.game.is_winnable(),
+            Expectation::Unwinnable => !self.game.is_winnable(),
+            Expectation::MinJumps(jumps) => self.game.min_jumps() == Some(jumps),
+        })
+    }
+}
+
+impl fmt::Display for Puzzle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(name) = &self.name {
+            writeln!(f, "name: {name}")?;
+        }
+        if self.game.rule != RuleKind::Symmetric {
+            writeln!(f, "rule: {}", self.game.rule)?;
+        }
+        write!(f, "{}", self.game)?;
+        if let Some(expected) = self.expected {
+            write!(f, "\nexpect: {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Puzzle {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut puzzles = parse_blocks(input)?;
+        match puzzles.len() {
+            0 => Err(ParseError::new(1, 1, ParseErrorKind::MissingKey("board"))),
+            1 => Ok(puzzles.pop().unwrap().1),
+            _ => {
+                let line = puzzles[1].0;
+                Err(ParseError::new(line, 1, ParseErrorKind::MultiplePuzzles))
+            }
+        }
+    }
+}
+
+impl<R> fmt::Display for JumpGame<R> {
+    /// Writes the board, starting index, and any non default boundary or goal. The rule is not
+    /// written, see [`Puzzle`] for that, and custom goals are written as `custom` which can not
+    /// be parsed back.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "board:")?;
+        for value in &self.board {
+            write!(f, " {value}")?;
+        }
+        write!(f, "\nstart: {}", self.starting_index)?;
+        if self.boundary == Boundary::Wrap {
+            write!(f, "\nboundary: wrap")?;
+        }
+        match &self.goal {
+            Goal::ZeroCell => Ok(()),
+            Goal::Index(indices) => {
+                write!(f, "\ngoal: index")?;
+                indices.iter().try_for_each(|index| write!(f, " {index}"))
+            }
+            Goal::Value(value) => write!(f, "\ngoal: value {value}"),
+            Goal::ExitRight => write!(f, "\ngoal: exit-right"),
+            Goal::Custom(_) => write!(f, "\ngoal: custom"),
+        }
+    }
+}
+
+impl FromStr for JumpGame {
+    type Err = ParseError;
+
+    /// Parses a single puzzle that uses the default rule, ignoring its name and expected answer.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::JumpGame;
+    /// let game: JumpGame = "board: 1 2 3 0 3 2\nstart: 4".parse().unwrap();
+    /// assert!(game.is_winnable());
+    /// assert_eq!(game.to_string().parse::<JumpGame>().unwrap().solve(), game.solve());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let puzzle: Puzzle = input.parse()?;
+        if puzzle.game.rule != RuleKind::Symmetric {
+            let line = input
+                .lines()
+                .position(|line| key_of(line) == Some("rule"))
+                .map_or(1, |index| index + 1);
+            return Err(ParseError::new(
+                line,
+                1,
+                ParseErrorKind::UnsupportedRule(puzzle.game.rule),
+            ));
+        }
+        Ok(puzzle.game.with_rule(SymmetricExact))
+    }
+}
+
+/// # Parses every puzzle in a file, in order.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::parse_puzzles;
+/// let puzzles = parse_puzzles("board: 1 0\nstart: 0\n---\nboard: 2 0\nstart: 0").unwrap();
+/// assert!(puzzles[0].game.is_winnable());
+/// assert!(!puzzles[1].game.is_winnable());
+///
+/// let error = parse_puzzles("board: 1 0\nstart: one").unwrap_err();
+/// assert_eq!((error.line, error.column), (2, 8));
+/// ```
+pub fn parse_puzzles(input: &str) -> Result<Vec<Puzzle>, ParseError> {
+    let blocks = parse_blocks(input)?;
+    Ok(blocks.into_iter().map(|(_, puzzle)| puzzle).collect())
+}
+
+/// # Writes puzzles in the format read by [`parse_puzzles`].
+pub fn write_puzzles(puzzles: &[Puzzle]) -> String {
+    puzzles
+        .iter()
+        .map(Puzzle::to_string)
+        .collect::<Vec<_>>()
+        .join("\n---\n")
+}
+
+/// # Where and why some puzzle text could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The 1-based line of the problem.
+    pub line: usize,
+    /// The 1-based column of the problem, in characters.
+    pub column: usize,
+    pub kind: ParseErrorKind,
+}
+
+impl ParseError {
+    fn new(line: usize, column: usize, kind: ParseErrorKind) -> Self {
+        Self { line, column, kind }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// # The reasons some puzzle text can fail to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    /// A line is not a `key: value` pair.
+    MissingColon,
+    /// A key that the format does not know about.
+    UnknownKey(String),
+    /// The same key appears twice in one puzzle.
+    DuplicateKey(String),
+    /// A required key is missing from a puzzle.
+    MissingKey(&'static str),
+    /// A value that should have been a whole number.
+    InvalidNumber(String),
+    /// A value that is not one of the allowed options for its key.
+    InvalidValue { key: &'static str, value: String },
+    /// The text holds more than one puzzle where only one was expected.
+    MultiplePuzzles,
+    /// A rule that can't be used by the type being parsed.
+    UnsupportedRule(RuleKind),
+    /// The puzzle parsed, but is not a valid game.
+    InvalidGame(JumpGameError),
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::MissingColon => write!(f, "expected a `key: value` line"),
+            ParseErrorKind::UnknownKey(key) => write!(f, "unknown key `{key}`"),
+            ParseErrorKind::DuplicateKey(key) => write!(f, "`{key}` is given more than once"),
+            ParseErrorKind::MissingKey(key) => write!(f, "missing `{key}`"),
+            ParseErrorKind::InvalidNumber(value) => write!(f, "`{value}` is not a number"),
+            ParseErrorKind::InvalidValue { key, value } => {
+                write!(f, "`{value}` is not a valid {key}")
+            }
+            ParseErrorKind::MultiplePuzzles => write!(f, "expected a single puzzle"),
+            ParseErrorKind::UnsupportedRule(rule) => {
+                write!(
+                    f,
+                    "the {rule} rule is not supported here, parse a Puzzle instead"
+                )
+            }
+            ParseErrorKind::InvalidGame(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+/// A value along with the (line, column) it was read from.
+type Located<T> = (T, (usize, usize));
+
+/// The keys read so far for a single puzzle.
+#[derive(Default)]
+struct PuzzleBuilder {
+    first_line: usize,
+    name: Option<Located<String>>,
+    board: Option<Located<Vec<usize>>>,
+    start: Option<Located<usize>>,
+    expected: Option<Located<Expectation>>,
+    rule: Option<Located<RuleKind>>,
+    boundary: Option<Located<Boundary>>,
+    goal: Option<Located<Goal>>,
+}
+
+impl PuzzleBuilder {
+    fn add(&mut self, line_number: usize, line: &str) -> Result<(), ParseError> {
+        let Some((key, value)) = line.split_once(':') else {
+            let column = column_of(line, line.trim_start());
+            return Err(ParseError::new(
+                line_number,
+                column,
+                ParseErrorKind::MissingColon,
+            ));
+        };
+        let key_column = column_of(line, line.trim_start());
+        let value_start = key.len() + 1 + (value.len() - value.trim_start().len());
+        let value_column = line[..value_start].chars().count() + 1;
+        let location = (line_number, value_column);
+        let value = value.trim();
+
+        let duplicate = || {
+            ParseError::new(
+                line_number,
+                key_column,
+                ParseErrorKind::DuplicateKey(key.trim().to_string()),
+            )
+        };
+        macro_rules! set {
+            ($field:ident, $value:expr) => {{
+                if self.$field.is_some() {
+                    return Err(duplicate());
+                }
+                self.$field = Some(($value, location));
+            }};
+        }
+
+        match key.trim() {
+            "name" => set!(name, value.to_string()),
+            "board" => set!(board, parse_numbers(value, location)?),
+            "start" => set!(start, parse_number(value, location)?),
+            "expect" => set!(expected, parse_expectation(value, location)?),
+            "rule" => set!(rule, parse_rule(value, location)?),
+            "boundary" => set!(boundary, parse_boundary(value, location)?),
+            "goal" => set!(goal, parse_goal(value, location)?),
+            other => {
+                return Err(ParseError::new(
+                    line_number,
+                    key_column,
+                    ParseErrorKind::UnknownKey(other.to_string()),
+                ))
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Puzzle, ParseError> {
+        let missing = |key| ParseError::new(self.first_line, 1, ParseErrorKind::MissingKey(key));
+        let (board, board_location) = self.board.ok_or_else(|| missing("board"))?;
+        let (starting_index, start_location) = self.start.ok_or_else(|| missing("start"))?;
+        let (goal, goal_location) = self.goal.unwrap_or((Goal::ZeroCell, board_location));
+
+        let game = JumpGame::try_with_goal(board, starting_index, goal).map_err(|error| {
+            let (line, column) = match error {
+                JumpGameError::StartingIndexOutOfBounds { .. } => start_location,
+                JumpGameError::EmptyBoard => board_location,
+                _ => goal_location,
+            };
+            ParseError::new(line, column, ParseErrorKind::InvalidGame(error))
+        })?;
+
+        Ok(Puzzle {
+            name: self.name.map(|(name, _)| name),
+            expected: self.expected.map(|(expected, _)| expected),
+            game: game
+                .with_rule(self.rule.map_or(RuleKind::Symmetric, |(rule, _)| rule))
+                .with_boundary(
+                    self.boundary
+                        .map_or(Boundary::Wall, |(boundary, _)| boundary),
+                ),
+        })
+    }
+}
+
+/// Splits the input into puzzles, pairing each with the line it starts on.
+fn parse_blocks(input: &str) -> Result<Vec<(usize, Puzzle)>, ParseError> {
+    let mut blocks = Vec::new();
+    let mut builder: Option<PuzzleBuilder> = None;
+
+    for (line_index, line) in input.lines().enumerate() {
+        let line_number = line_index + 1;
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') {
+            continue;
+        }
+        if trimmed == "---" {
+            if let Some(builder) = builder.take() {
+                blocks.push((builder.first_line, builder.finish()?));
+            }
+            continue;
+        }
+        builder
+            .get_or_insert_with(|| PuzzleBuilder {
+                first_line: line_number,
+                ..Default::default()
+            })
+            .add(line_number, line)?;
+    }
+    if let Some(builder) = builder {
+        blocks.push((builder.first_line, builder.finish()?));
+    }
+
+    Ok(blocks)
+}
+
+/// The 1-based column at which `part`, a slice of `line`, starts.
+fn column_of(line: &str, part: &str) -> usize {
+    let offset = part.as_ptr() as usize - line.as_ptr() as usize;
+    line[..offset].chars().count() + 1
+}
+
+/// The key of a `key: value` line, if it has one.
+fn key_of(line: &str) -> Option<&str> {
+    line.split_once(':').map(|(key, _)| key.trim())
+}
+
+/// Splits a value into whitespace separated words, each with its column.
+fn words(value: &str, (line, column): (usize, usize)) -> impl Iterator<Item = Located<&str>> {
+    value
+        .split_whitespace()
+        .map(move |word| (word, (line, column + column_of(value, word) - 1)))
+}
+
+fn parse_number(value: &str, location: (usize, usize)) -> Result<usize, ParseError> {
+    let (line, column) = location;
+    value
+        .parse()
+        .map_err(|_| ParseError::new(line, column, ParseErrorKind::InvalidNumber(value.into())))
+}
+
+fn parse_numbers(value: &str, location: (usize, usize)) -> Result<Vec<usize>, ParseError> {
+    words(value, location)
+        .map(|(word, location)| parse_number(word, location))
+        .collect()
+}
+
+fn invalid(key: &'static str, value: &str, (line, column): (usize, usize)) -> ParseError {
+    let value = value.to_string();
+    ParseError::new(line, column, ParseErrorKind::InvalidValue { key, value })
+}
+
+fn parse_expectation(value: &str, location: (usize, usize)) -> Result<Expectation, ParseError> {
+    match value {
+        "winnable" => Ok(Expectation::Winnable),
+        "unwinnable" => Ok(Expectation::Unwinnable),
+        jumps if jumps.chars().all(|c| c.is_ascii_digit()) => {
+            parse_number(jumps, location).map(Expectation::MinJumps)
+        }
+        other => Err(invalid("expectation", other, location)),
+    }
+}
+
+fn parse_rule(value: &str, location: (usize, usize)) -> Result<RuleKind, ParseError> {
+    match value {
+        "symmetric" => Ok(RuleKind::Symmetric),
+        "forward" => Ok(RuleKind::Forward),
+        "bidirectional" => Ok(RuleKind::Bidirectional),
+        other => Err(invalid("rule", other, location)),
+    }
+}
+
+fn parse_boundary(value: &str, location: (usize, usize)) -> Result<Boundary, ParseError> {
+    match value {
+        "wall" => Ok(Boundary::Wall),
+        "wrap" => Ok(Boundary::Wrap),
+        other => Err(invalid("boundary", other, location)),
+    }
+}
+
+fn parse_goal(value: &str, location: (usize, usize)) -> Result<Goal, ParseError> {
+    let mut words = words(value, location);
+    let Some((kind, _)) = words.next() else {
+        return Err(invalid("goal", value, location));
+    };
+    let goal = match kind {
+        "zero" => Goal::ZeroCell,
+        "exit-right" => Goal::ExitRight,
+        "index" => Goal::Index(
+            words
+                .by_ref()
+                .map(|(word, location)| parse_number(word, location))
+                .collect::<Result<BTreeSet<_>, _>>()?,
+        ),
+        "value" => match words.next() {
+            Some((word, location)) => Goal::Value(parse_number(word, location)?),
+            None => return Err(invalid("goal", value, location)),
+        },
+        _ => return Err(invalid("goal", value, location)),
+    };
+    match words.next() {
+        Some((word, location)) => Err(invalid("goal", word, location)),
+        None => Ok(goal),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test]
+    fn fixtures_match_their_expected_answers() {
+        let puzzles = parse_puzzles(include_str!("../../fixtures/jump_game.txt")).unwrap();
+        assert!(!puzzles.is_empty());
+        for puzzle in puzzles {
+            assert_eq!(
+                puzzle.meets_expectation(),
+                Some(true),
+                "{}",
+                puzzle.name.as_deref().unwrap_or("unnamed puzzle")
+            );
+        }
+    }
+
+    #[test]
+    fn puzzles_round_trip_through_display() {
+        let input = "\
+name: Everything
+rule: bidirectional
+board: 2 3 1 1 4
+start: 3
+boundary: wrap
+goal: index 0 2
+expect: 1
+---
+board: 2 0 1
+start: 0
+goal: exit-right";
+        let puzzles = parse_puzzles(input).unwrap();
+        assert_eq!(write_puzzles(&puzzles), input);
+    }
+
+    #[test]
+    fn comments_and_blank_lines_are_ignored() {
+        let input = "\
+# a comment
+
+board: 1 0
+   # an indented comment
+start: 0
+
+---
+---
+board: 0
+start: 0
+";
+        assert_eq!(parse_puzzles(input).unwrap().len(), 2);
+    }
+
+    #[test_case("board 1 0", 1, 1, ParseErrorKind::MissingColon)]
+    #[test_case("board: 1 0\nstart: 0\nsize: 2", 3, 1, ParseErrorKind::UnknownKey("size".into()))]
+    #[test_case("board: 1 0\n  board: 0", 2, 3, ParseErrorKind::DuplicateKey("board".into()))]
+    #[test_case("board: 1 x 0\nstart: 0", 1, 10, ParseErrorKind::InvalidNumber("x".into()))]
+    #[test_case("board: 1 0\nstart: -1", 2, 8, ParseErrorKind::InvalidNumber("-1".into()))]
+    #[test_case("board: 1 0", 1, 1, ParseErrorKind::MissingKey("start"))]
+    #[test_case(
+        "board: 1 0\nstart: 0\n---\nstart: 0",
+        4,
+        1,
+        ParseErrorKind::MissingKey("board")
+    )]
+    #[test_case("board: 1 0\nstart: 0\nrule: sideways", 3, 7, ParseErrorKind::InvalidValue { key: "rule", value: "sideways".into() })]
+    #[test_case("board: 1 0\nstart: 0\nboundary: soft", 3, 11, ParseErrorKind::InvalidValue { key: "boundary", value: "soft".into() })]
+    #[test_case("board: 1 0\nstart: 0\ngoal: value", 3, 7, ParseErrorKind::InvalidValue { key: "goal", value: "value".into() })]
+    #[test_case("board: 1 0\nstart: 0\ngoal: value 1 2", 3, 15, ParseErrorKind::InvalidValue { key: "goal", value: "2".into() })]
+    #[test_case("board: 1 0\nstart: 0\ngoal: custom", 3, 7, ParseErrorKind::InvalidValue { key: "goal", value: "custom".into() })]
+    #[test_case("board: 1 0\nstart: 0\nexpect: maybe", 3, 9, ParseErrorKind::InvalidValue { key: "expectation", value: "maybe".into() })]
+    #[test_case(
+        "board:\nstart: 0",
+        1,
+        7,
+        ParseErrorKind::InvalidGame(JumpGameError::EmptyBoard)
+    )]
+    #[test_case("board: 1 0\nstart: 2", 2, 8, ParseErrorKind::InvalidGame(JumpGameError::StartingIndexOutOfBounds { starting_index: 2, board_len: 2 }))]
+    #[test_case("board: 1 2\nstart: 0", 1, 8, ParseErrorKind::InvalidGame(JumpGameError::MissingZero { board_len: 2 }))]
+    #[test_case("board: 1 2\nstart: 0\ngoal: index 1 5", 3, 7, ParseErrorKind::InvalidGame(JumpGameError::GoalIndexOutOfBounds { index: 5, board_len: 2 }))]
+    fn errors_point_at_the_problem(input: &str, line: usize, column: usize, kind: ParseErrorKind) {
+        assert_eq!(
+            parse_puzzles(input).unwrap_err(),
+            ParseError { line, column, kind }
+        );
+    }
+
+    #[test]
+    fn a_single_puzzle_must_not_have_more() {
+        let error = "board: 0\nstart: 0\n---\nboard: 0\nstart: 0"
+            .parse::<Puzzle>()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(4, 1, ParseErrorKind::MultiplePuzzles)
+        );
+    }
+
+    #[test]
+    fn jump_games_only_parse_the_default_rule() {
+        let error = "board: 1 0\nstart: 0\nrule: forward"
+            .parse::<JumpGame>()
+            .unwrap_err();
+        assert_eq!(
+            error,
+            ParseError::new(3, 1, ParseErrorKind::UnsupportedRule(RuleKind::Forward))
+        );
+    }
+
+    #[test]
+    fn errors_display_their_location() {
+        let error = parse_puzzles("board: 1 x").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 10: `x` is not a number");
+    }
+
+    #[test]
+    fn rule_kinds_jump_like_the_rules_they_name() {
+        let board = vec![3, 1, 2, 5, 0];
+        let game = JumpGame::new(board, 0);
+        for (kind, expected) in [
+            (RuleKind::Symmetric, game.clone().min_jumps()),
+            (
+                RuleKind::Forward,
+                game.clone().with_rule(ForwardUpTo).min_jumps(),
+            ),
+            (
+                RuleKind::Bidirectional,
+                game.clone().with_rule(BidirectionalUpTo).min_jumps(),
+            ),
+        ] {
+            assert_eq!(game.clone().with_rule(kind).min_jumps(), expected);
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use super::{validate_board, JumpGameError};
+
+/// # The classic forward-only jump game, better known as Jump Game I and II.
+///
+/// The player starts on the first index, each cell holds the longest jump forward that can be
+/// made from it, and reaching the last index wins. The same game can be played as a
+/// [`JumpGame`](super::JumpGame) with the [`ForwardUpTo`](super::ForwardUpTo) rule and the last
+/// index as its goal, but this version solves it greedily in O(n) time.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::ForwardJumpGame;
+/// let game = ForwardJumpGame::new(vec![2, 3, 1, 1, 4]);
+/// assert!(game.is_winnable());
+/// assert_eq!(game.min_jumps(), Some(2));
+/// assert_eq!(game.shortest_path(), Some(vec![0, 1, 4]));
+///
+/// let game = ForwardJumpGame::new(vec![3, 2, 1, 0, 4]);
+/// assert!(!game.is_winnable());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ForwardJumpGame {
+    board: Vec<usize>,
+}
+
+impl ForwardJumpGame {
+    /// # Creates a new ForwardJumpGame with the given board.
+    ///
+    /// Panics if the board is empty, see [`ForwardJumpGame::try_new`] for the fallible version.
+    pub fn new(board: Vec<usize>) -> Self {
+        Self::try_new(board).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new ForwardJumpGame, returning an error instead of panicking when the board
+    /// is empty.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{ForwardJumpGame, JumpGameError};
+    /// assert!(ForwardJumpGame::try_new(vec![0]).is_ok());
+    /// assert_eq!(ForwardJumpGame::try_new(vec![]), Err(JumpGameError::EmptyBoard));
+    /// ```
+    pub fn try_new(board: Vec<usize>) -> Result<Self, JumpGameError> {
+        validate_board(&board)?;
+        Ok(Self { board })
+    }
+
+    /// # The values on the board.
+    pub fn board(&self) -> &[usize] {
+        &self.board
+    }
+
+    /// # Checks to see if the last index can be reached (Jump Game I).
+    ///
+    /// Keeps track of the furthest index reached so far, which is winnable as soon as it gets to
+    /// the last index and lost if the scan ever passes it.
+    pub fn is_winnable(&self) -> bool {
+        let last = self.board.len() - 1;
+        let mut furthest = 0;
+        for (index, &value) in self.board.iter().enumerate() {
+            if index > furthest {
+                return false;
+            }
+            furthest = furthest.max(index.saturating_add(value));
+            if furthest >= last {
+                return true;
+            }
+        }
+        // a single cell board is won before it starts
+        true
+    }
+
+    /// # Finds a winning path to the last index (Jump Game I).
+    ///
+    /// Works backwards from the last index, keeping track of the earliest index known to win.
+    /// Each index that can jump to it becomes the new earliest winner, and the path follows
+    /// those jumps from the start. The path is not always the shortest, see
+    /// [`ForwardJumpGame::shortest_path`] for that.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::ForwardJumpGame;
+    /// let game = ForwardJumpGame::new(vec![2, 3, 1, 1, 4]);
+    /// assert_eq!(game.solve(), Some(vec![0, 1, 2, 3, 4]));
+    /// ```
+    pub fn solve(&self) -> Option<Vec<usize>> {
+        let last = self.board.len() - 1;
+        let mut next = vec![last; self.board.len()];
+        let mut earliest_winner = last;
+        for index in (0..last).rev() {
+            if index.saturating_add(self.board[index]) >= earliest_winner {
+                next[index] = earliest_winner;
+                earliest_winner = index;
+            }
+        }
+        if earliest_winner != 0 {
+            return None;
+        }
+
+        let mut path = vec![0];
+        while path[path.len() - 1] != last {
+            path.push(next[path[path.len() - 1]]);
+        }
+        Some(path)
+    }
+
+    /// # Finds the fewest number of jumps needed to reach the last index (Jump Game II).
+    ///
+    /// Scans the board one jump count at a time, where every index up to `end` can be reached in
+    /// `jumps` jumps and the furthest any of them can get to is where the next jump count ends.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::ForwardJumpGame;
+    /// assert_eq!(ForwardJumpGame::new(vec![2, 3, 0, 1, 4]).min_jumps(), Some(2));
+    /// assert_eq!(ForwardJumpGame::new(vec![1, 0, 1]).min_jumps(), None);
+    /// ```
+    pub fn min_jumps(&self) -> Option<usize> {
+        let last = self.board.len() - 1;
+        let mut jumps = 0;
+        let mut end = 0;
+        let mut furthest = 0;
+        for (index, &value) in self.board.iter().enumerate().take(last) {
+            furthest = furthest.max(index.saturating_add(value));
+            if index == end {
+                if furthest <= index {
+                    // nothing gets past here
+                    return None;
+                }
+                jumps += 1;
+                end = furthest;
+                if end >= last {
+                    break;
+                }
+            }
+        }
+        Some(jumps)
+    }
+
+    /// # Finds a path with the fewest jumps to the last index (Jump Game II).
+    ///
+    /// Each jump goes to whichever newly reachable index can itself jump the furthest.
+    pub fn shortest_path(&self) -> Option<Vec<usize>> {
+        let last = self.board.len() - 1;
+        let reach = |index: usize| index.saturating_add(self.board[index]);
+        let mut path = vec![0];
+        // every index up to here has already been considered for an earlier jump
+        let mut scanned = 0;
+
+        loop {
+            let current = path[path.len() - 1];
+            if current == last {
+                return Some(path);
+            }
+            let end = reach(current);
+            if end >= last {
+                path.push(last);
+                return Some(path);
+            }
+            let next = (scanned + 1..=end).max_by_key(|&index| (reach(index), index))?;
+            if reach(next) <= end {
+                // nothing reachable gets any further
+                return None;
+            }
+            scanned = end;
+            path.push(next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![2, 3, 1, 1, 4], true)]
+    #[test_case(vec![3, 2, 1, 0, 4], false)]
+    #[test_case(vec![0], true)]
+    #[test_case(vec![0, 1], false)]
+    #[test_case(vec![1, 0], true)]
+    #[test_case(vec![2, 0, 0], true)]
+    #[test_case(vec![1, 1, 0, 1], false)]
+    #[test_case(vec![5, 0, 0, 0, 0, 0], true)]
+    #[test_case(vec![1, 2, 0, 0, 1], false)]
+    #[test_case(vec![usize::MAX, 0, 0], true)]
+    fn test_cases(board: Vec<usize>, expected: bool) {
+        let game = ForwardJumpGame::new(board);
+        assert_eq!(game.is_winnable(), expected);
+        assert_eq!(game.solve().is_some(), expected);
+        assert_eq!(game.min_jumps().is_some(), expected);
+        assert_eq!(game.shortest_path().is_some(), expected);
+    }
+
+    #[test_case(vec![2, 3, 1, 1, 4], Some(vec![0, 1, 4]))]
+    #[test_case(vec![2, 3, 0, 1, 4], Some(vec![0, 1, 4]))]
+    #[test_case(vec![0], Some(vec![0]))]
+    #[test_case(vec![1, 1, 1, 1], Some(vec![0, 1, 2, 3]))]
+    #[test_case(vec![1, 4, 1, 1, 1, 1, 1], Some(vec![0, 1, 5, 6]))]
+    #[test_case(vec![3, 1, 4, 1, 1, 1, 1, 1], Some(vec![0, 2, 6, 7]))]
+    #[test_case(vec![10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 1, 0], Some(vec![0, 10, 11]))]
+    #[test_case(vec![3, 2, 1, 0, 4], None)]
+    fn shortest_path_cases(board: Vec<usize>, expected: Option<Vec<usize>>) {
+        let game = ForwardJumpGame::new(board);
+        assert_eq!(
+            game.min_jumps(),
+            expected.as_ref().map(|path| path.len() - 1)
+        );
+        assert_eq!(game.shortest_path(), expected);
+    }
+
+    #[test_case(vec![2, 3, 1, 1, 4], Some(vec![0, 1, 2, 3, 4]))]
+    #[test_case(vec![4, 0, 0, 0, 0], Some(vec![0, 4]))]
+    #[test_case(vec![2, 2, 0, 0], Some(vec![0, 1, 3]))]
+    #[test_case(vec![1, 0, 1], None)]
+    fn solve_cases(board: Vec<usize>, expected: Option<Vec<usize>>) {
+        assert_eq!(ForwardJumpGame::new(board).solve(), expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Board must have at least one element")]
+    fn empty_boards_panic() {
+        ForwardJumpGame::new(vec![]);
+    }
+}
@@ -0,0 +1,406 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+use super::{
+    validate, BoardAnalysis, Boundary, Goal, Jump, JumpGameError, JumpRule, Scratch, SymmetricExact,
+};
+
+/// # An unsigned integer type that can hold the jump length of a cell.
+///
+/// Implemented for every unsigned integer type, so boards can be stored as compactly as their
+/// values allow.
+pub trait BoardValue: Copy {
+    /// Widens the value so it can be compared with a [`Goal`] value.
+    fn to_usize(self) -> usize;
+
+    /// Widens the value so it can be used as a jump length on a board `len` cells long.
+    ///
+    /// Values too big for a usize are swapped for one that still jumps past either end of the
+    /// board and lands on the same cell when it wraps around.
+    fn to_jump_length(self, len: usize) -> usize;
+}
+
+macro_rules! impl_board_value {
+    ($($value:ty),*) => {
+        $(
+            impl BoardValue for $value {
+                fn to_usize(self) -> usize {
+                    // a value too big for usize can never match a goal value, so saturating is
+                    // as good as exact
+                    usize::try_from(self).unwrap_or(usize::MAX)
+                }
+
+                #[allow(clippy::cast_possible_truncation)]
+                fn to_jump_length(self, len: usize) -> usize {
+                    // len + value % len is at least len and is congruent to value modulo len,
+                    // and the value only fails to fit when len fits in its type
+                    usize::try_from(self).unwrap_or_else(|_| len + (self % len as $value) as usize)
+                }
+            }
+        )*
+    };
+}
+
+impl_board_value!(u8, u16, u32, u64, u128, usize);
+
+/// # A JumpGame played directly on a borrowed board.
+///
+/// The board can be any slice of [`BoardValue`]s, so large boards stored as `u8` or `u16` never
+/// need to be widened or copied into a [`JumpGame`](super::JumpGame) before they are solved.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::JumpGameRef;
+/// let board: &[u8] = &[1, 2, 3, 0, 3, 2];
+/// let game = JumpGameRef::new(board, 0);
+/// assert!(game.is_winnable());
+/// assert_eq!(game.shortest_path(), Some(vec![0, 1, 3]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct JumpGameRef<'a, T = usize, R = SymmetricExact> {
+    pub(super) board: &'a [T],
+    pub(super) starting_index: usize,
+    pub(super) rule: R,
+    pub(super) boundary: Boundary,
+    pub(super) goal: Cow<'a, Goal>,
+}
+
+impl<'a, T: BoardValue> JumpGameRef<'a, T> {
+    /// # Creates a new JumpGameRef over the given board and starting position.
+    ///
+    /// Panics if the board is invalid, see [`JumpGameRef::try_new`] for the fallible version.
+    pub fn new(board: &'a [T], starting_index: usize) -> Self {
+        Self::try_new(board, starting_index).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new JumpGameRef, returning an error instead of panicking when the board is
+    /// invalid.
+    ///
+    /// The board is validated the same way as [`JumpGame::try_new`](super::JumpGame::try_new).
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGameError, JumpGameRef};
+    /// assert_eq!(
+    ///     JumpGameRef::try_new(&[1u16, 2, 3], 0).err(),
+    ///     Some(JumpGameError::MissingZero { board_len: 3 })
+    /// );
+    /// ```
+    pub fn try_new(board: &'a [T], starting_index: usize) -> Result<Self, JumpGameError> {
+        Self::try_with_goal(board, starting_index, Goal::ZeroCell)
+    }
+
+    /// # Creates a new JumpGameRef that is won by reaching `goal`, returning an error instead of
+    /// panicking when the board is invalid.
+    pub fn try_with_goal(
+        board: &'a [T],
+        starting_index: usize,
+        goal: Goal,
+    ) -> Result<Self, JumpGameError> {
+        validate(board, starting_index, &goal)?;
+        Ok(Self {
+            board,
+            starting_index,
+            rule: SymmetricExact,
+            boundary: Boundary::Wall,
+            goal: Cow::Owned(goal),
+        })
+    }
+}
+
+impl<'a, T: BoardValue, R: JumpRule> JumpGameRef<'a, T, R> {
+    /// # Replaces the rule deciding where each cell can jump to.
+    pub fn with_rule<S: JumpRule>(self, rule: S) -> JumpGameRef<'a, T, S> {
+        JumpGameRef {
+            board: self.board,
+            starting_index: self.starting_index,
+            rule,
+            boundary: self.boundary,
+            goal: self.goal,
+        }
+    }
+
+    /// # Changes what happens when a jump goes past either end of the board.
+    pub fn with_boundary(self, boundary: Boundary) -> Self {
+        Self { boundary, ..self }
+    }
+
+    /// # Checks to see if the game is winnable, see [`JumpGame::is_winnable`](super::JumpGame::is_winnable).
+    pub fn is_winnable(&self) -> bool {
+        self.is_winnable_with(&mut Scratch::new())
+    }
+
+    /// # Checks to see if the game is winnable, reusing the buffers in `scratch`.
+    pub fn is_winnable_with(&self, scratch: &mut Scratch) -> bool {
+        if self.is_goal_cell(self.starting_index) {
+            return true;
+        }
+
+        scratch.reset(self.board.len());
+        scratch.visit(self.starting_index);
+        scratch.stack.push(self.starting_index);
+
+        while let Some(current_index) = scratch.stack.pop() {
+            let mut won = false;
+            self.rule.targets(
+                current_index,
+                self.jump_length(current_index),
+                self.board.len(),
+                |jump| {
+                    match self.land(jump) {
+                        // only queue each index once, the first time it is found
+                        Landing::Cell(next_index) if scratch.visit(next_index) => {
+                            if self.is_goal_cell(next_index) {
+                                won = true;
+                            } else {
+                                scratch.stack.push(next_index);
+                            }
+                        }
+                        Landing::Cell(_) | Landing::Off => {}
+                        Landing::Exit => won = true,
+                    }
+                },
+            );
+            if won {
+                // WINNER!
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// # Finds a winning path through the game, see [`JumpGame::solve`](super::JumpGame::solve).
+    pub fn solve(&self) -> Option<Vec<usize>> {
+        // each entry is an index to visit along with the index we jumped from to get there
+        let mut stack = Vec::<(usize, Option<usize>)>::new();
+        let mut visited = HashSet::<usize>::new();
+        let mut parents = HashMap::<usize, usize>::new();
+
+        stack.push((self.starting_index, None));
+
+        while let Some((current_index, parent)) = stack.pop() {
+            if !visited.insert(current_index) {
+                // we've been here already - prevent infinite loops
+                continue;
+            }
+            if let Some(parent) = parent {
+                parents.insert(current_index, parent);
+            }
+
+            if self.is_goal_cell(current_index) {
+                // WINNER!
+                return Some(build_path(&parents, current_index));
+            }
+
+            let mut exited = false;
+            self.rule.targets(
+                current_index,
+                self.jump_length(current_index),
+                self.board.len(),
+                |jump| {
+                    match self.land(jump) {
+                        Landing::Cell(next_index) => stack.push((next_index, Some(current_index))),
+                        Landing::Exit => exited = true,
+                        // jumps that leave the board are dead ends
+                        Landing::Off => {}
+                    }
+                },
+            );
+            if exited {
+                // WINNER! (by jumping off the board)
+                return Some(build_path(&parents, current_index));
+            }
+        }
+
+        None
+    }
+
+    /// # Finds the fewest number of jumps needed to reach a goal, see
+    /// [`JumpGame::min_jumps`](super::JumpGame::min_jumps).
+    pub fn min_jumps(&self) -> Option<usize> {
+        self.breadth_first_search().map(|(_, jumps)| jumps)
+    }
+
+    /// # Finds a path with the fewest jumps to a goal, see
+    /// [`JumpGame::shortest_path`](super::JumpGame::shortest_path).
+    pub fn shortest_path(&self) -> Option<Vec<usize>> {
+        self.breadth_first_search().map(|(path, _)| path)
+    }
+
+    /// # Works out winnability and distance-to-the-nearest-goal for every index of the board.
+    pub fn analyze(&self) -> BoardAnalysis {
+        BoardAnalysis::build(self.board, &self.rule, self.boundary, &self.goal)
+    }
+
+    /// Finds a shortest winning path along with the number of jumps it takes.
+    fn breadth_first_search(&self) -> Option<(Vec<usize>, usize)> {
+        if self.is_goal_cell(self.starting_index) {
+            return Some((vec![self.starting_index], 0));
+        }
+
+        let mut queue = VecDeque::<usize>::new();
+        let mut visited = HashSet::<usize>::new();
+        let mut parents = HashMap::<usize, usize>::new();
+
+        queue.push_back(self.starting_index);
+        visited.insert(self.starting_index);
+
+        while let Some(current_index) = queue.pop_front() {
+            // goals are checked as they are found, so every index queued before this one is
+            // at most as far away and the first goal found is the nearest
+            let mut winner = None;
+            self.rule.targets(
+                current_index,
+                self.jump_length(current_index),
+                self.board.len(),
+                |jump| {
+                    if winner.is_some() {
+                        return;
+                    }
+                    match self.land(jump) {
+                        Landing::Cell(next_index) => {
+                            if visited.insert(next_index) {
+                                parents.insert(next_index, current_index);
+                                if self.is_goal_cell(next_index) {
+                                    winner = Some((next_index, 0));
+                                }
+                                queue.push_back(next_index);
+                            }
+                        }
+                        Landing::Exit => winner = Some((current_index, 1)),
+                        Landing::Off => {}
+                    }
+                },
+            );
+
+            if let Some((end, exit_jumps)) = winner {
+                let path = build_path(&parents, end);
+                let jumps = path.len() - 1 + exit_jumps;
+                return Some((path, jumps));
+            }
+        }
+
+        None
+    }
+
+    /// The jump length of the cell at `index`.
+    pub(super) fn value(&self, index: usize) -> usize {
+        self.board[index].to_usize()
+    }
+
+    /// The length of the jumps from the cell at `index`, see [`BoardValue::to_jump_length`].
+    pub(super) fn jump_length(&self, index: usize) -> usize {
+        self.board[index].to_jump_length(self.board.len())
+    }
+
+    /// Checks whether landing on `index` wins the game.
+    pub(super) fn is_goal_cell(&self, index: usize) -> bool {
+        self.goal.is_goal_cell(index, self.value(index))
+    }
+
+    /// Works out where a jump ends up.
+    pub(super) fn land(&self, jump: Jump) -> Landing {
+        match self.boundary.resolve(jump, self.board.len()) {
+            Some(index) => Landing::Cell(index),
+            None if self.goal.is_winning_exit(jump.target(), self.board.len()) => Landing::Exit,
+            None => Landing::Off,
+        }
+    }
+}
+
+/// Where a single jump ends up.
+pub(super) enum Landing {
+    /// On the cell at the given index.
+    Cell(usize),
+    /// Off the board, winning the game.
+    Exit,
+    /// Off the board, which is a dead end.
+    Off,
+}
+
+/// Walks the parent links back from `end` and returns the path in jump order.
+pub(super) fn build_path(parents: &HashMap<usize, usize>, end: usize) -> Vec<usize> {
+    let mut path = vec![end];
+    let mut current = end;
+    while let Some(&parent) = parents.get(&current) {
+        path.push(parent);
+        current = parent;
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{ForwardUpTo, JumpGame};
+    use test_case::test_case;
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2])]
+    #[test_case(vec![1, 7, 3, 0, 3, 2])]
+    #[test_case(vec![1, 1, 6, 0, 2, 2, 2])]
+    fn narrow_boards_match_usize_boards(board: Vec<usize>) {
+        let narrow: Vec<u8> = board.iter().map(|&value| value as u8).collect();
+        for starting_index in 0..board.len() {
+            let game = JumpGame::new(board.clone(), starting_index);
+            let game_ref = JumpGameRef::new(&narrow, starting_index);
+            assert_eq!(game_ref.is_winnable(), game.is_winnable());
+            assert_eq!(game_ref.solve(), game.solve());
+            assert_eq!(game_ref.shortest_path(), game.shortest_path());
+            assert_eq!(game_ref.analyze(), game.analyze());
+        }
+    }
+
+    #[test]
+    fn rules_and_boundaries_apply_to_borrowed_boards() {
+        let board: &[u16] = &[3, 1, 2, 5, 0];
+        let game = JumpGameRef::new(board, 0);
+        assert!(!game.is_winnable());
+        assert!(game.clone().with_rule(ForwardUpTo).is_winnable());
+        assert!(game.with_boundary(Boundary::Wrap).is_winnable());
+    }
+
+    #[test_case(&[], 0, JumpGameError::EmptyBoard)]
+    #[test_case(&[1, 0], 2, JumpGameError::StartingIndexOutOfBounds { starting_index: 2, board_len: 2 })]
+    #[test_case(&[1, 2, 3], 0, JumpGameError::MissingZero { board_len: 3 })]
+    fn try_new_rejects_invalid_boards(
+        board: &[u32],
+        starting_index: usize,
+        expected: JumpGameError,
+    ) {
+        assert_eq!(
+            JumpGameRef::try_new(board, starting_index).err(),
+            Some(expected)
+        );
+    }
+
+    #[test]
+    fn u64_values_past_the_board_are_dead_ends() {
+        let board: &[u64] = &[u64::from(u32::MAX), 0];
+        assert!(!JumpGameRef::new(board, 0).is_winnable());
+    }
+
+    #[test]
+    fn u128_values_past_usize_jump_exactly() {
+        let huge = u128::from(u64::MAX) + 2;
+        let board: &[u128] = &[huge, 0];
+        let game = JumpGameRef::new(board, 0);
+        assert!(!game.is_winnable());
+        assert_eq!(
+            game.with_boundary(Boundary::Wrap).shortest_path(),
+            Some(vec![0, 1])
+        );
+
+        // huge is 2 more than a multiple of 3, where usize::MAX is a multiple of 3
+        let board: &[u128] = &[huge, 7, 0];
+        let game = JumpGameRef::new(board, 0).with_boundary(Boundary::Wrap);
+        assert_eq!(game.shortest_path(), Some(vec![0, 2]));
+        assert_eq!(game.analyze().distance(0), Some(1));
+
+        let board: &[u128] = &[1, huge, 1];
+        let game = JumpGameRef::try_with_goal(board, 0, Goal::ExitRight).unwrap();
+        assert_eq!(game.shortest_path(), Some(vec![0, 1]));
+        assert_eq!(game.min_jumps(), Some(2));
+    }
+}
@@ -0,0 +1,247 @@
+use super::{BoardAnalysis, JumpGame};
+
+/// # What kind of JumpGame a [`JumpGameGenerator`] should produce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// The game can be won from its starting index, which is not itself a 0, so winning takes
+    /// at least one jump.
+    Winnable,
+    /// The game can not be won.
+    Unwinnable,
+    /// The game can be won, and the shortest path takes at least this many jumps.
+    MinJumps(usize),
+}
+
+impl Target {
+    /// Checks the game against the target using the solvers.
+    fn is_met_by(self, game: &JumpGame) -> bool {
+        let min_jumps = game.min_jumps();
+        match self {
+            Target::Winnable => min_jumps.is_some_and(|jumps| jumps > 0),
+            Target::Unwinnable => min_jumps.is_none(),
+            Target::MinJumps(at_least) => min_jumps.is_some_and(|jumps| jumps >= at_least),
+        }
+    }
+}
+
+/// # Generates random JumpGames, reproducibly from a seed.
+///
+/// Each board gets a single 0 and random jump lengths everywhere else, then a starting index is
+/// picked that matches the [`Target`]. Boards that have no suitable starting index are thrown
+/// away and another is tried.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::{JumpGameGenerator, Target};
+/// let mut generator = JumpGameGenerator::new(42);
+/// let game = generator.generate(10, Target::MinJumps(3)).unwrap();
+/// assert!(game.min_jumps().unwrap() >= 3);
+///
+/// // the same seed always generates the same games
+/// let mut generator = JumpGameGenerator::new(42);
+/// let again = generator.generate(10, Target::MinJumps(3)).unwrap();
+/// assert_eq!(game.solve(), again.solve());
+/// ```
+#[derive(Debug, Clone)]
+pub struct JumpGameGenerator {
+    state: u64,
+    max_value: Option<usize>,
+    max_attempts: usize,
+}
+
+impl JumpGameGenerator {
+    /// # Creates a new generator from the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: seed,
+            max_value: None,
+            max_attempts: 1_000,
+        }
+    }
+
+    /// # Limits the jump length of every cell to at most `max_value`.
+    ///
+    /// By default jumps can be up to one less than the length of the board.
+    pub fn with_max_value(self, max_value: usize) -> Self {
+        Self {
+            max_value: Some(max_value.max(1)),
+            ..self
+        }
+    }
+
+    /// # Limits how many boards are tried before [`JumpGameGenerator::generate`] gives up.
+    pub fn with_max_attempts(self, max_attempts: usize) -> Self {
+        Self {
+            max_attempts,
+            ..self
+        }
+    }
+
+    /// # Generates a JumpGame with a board of length `len` that matches `target`.
+    ///
+    /// Returns `None` if no matching game was found, either because one can't exist on a board
+    /// that short or because the generator ran out of attempts.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGameGenerator, Target};
+    /// let mut generator = JumpGameGenerator::new(7);
+    /// assert!(!generator.generate(8, Target::Unwinnable).unwrap().is_winnable());
+    /// assert!(generator.generate(8, Target::Winnable).unwrap().is_winnable());
+    ///
+    /// // a single cell board is always won before it starts
+    /// assert!(generator.generate(1, Target::Unwinnable).is_none());
+    /// ```
+    pub fn generate(&mut self, len: usize, target: Target) -> Option<JumpGame> {
+        if len == 0 {
+            return None;
+        }
+        let max_value = self.max_value.unwrap_or(len - 1).max(1);
+
+        for _ in 0..self.max_attempts {
+            let zero_index = self.below(len);
+            let board: Vec<usize> = (0..len)
+                .map(|index| {
+                    if index == zero_index {
+                        0
+                    } else {
+                        1 + self.below(max_value)
+                    }
+                })
+                .collect();
+
+            let analysis = BoardAnalysis::new(&board);
+            let candidates: Vec<usize> = analysis
+                .distances()
+                .iter()
+                .enumerate()
+                .filter(|(_, distance)| match target {
+                    Target::Winnable => distance.is_some_and(|distance| distance > 0),
+                    Target::Unwinnable => distance.is_none(),
+                    Target::MinJumps(min_jumps) => {
+                        distance.is_some_and(|distance| distance >= min_jumps)
+                    }
+                })
+                .map(|(index, _)| index)
+                .collect();
+            if candidates.is_empty() {
+                continue;
+            }
+
+            let starting_index = candidates[self.below(candidates.len())];
+            let game = JumpGame::new(board, starting_index);
+            // the start was picked from the analysis, so only hand the game out if the solvers
+            // agree with it
+            if !target.is_met_by(&game) {
+                continue;
+            }
+            return Some(game);
+        }
+
+        None
+    }
+
+    /// Returns a random number in `0..bound` using SplitMix64, which is tiny and stable across
+    /// platforms and releases so seeds keep generating the same games.
+    fn below(&mut self, bound: usize) -> usize {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^= z >> 31;
+        (z % bound as u64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(2)]
+    #[test_case(5)]
+    #[test_case(20)]
+    #[test_case(100)]
+    fn generates_winnable_games(len: usize) {
+        let mut generator = JumpGameGenerator::new(len as u64);
+        for _ in 0..20 {
+            let game = generator.generate(len, Target::Winnable).unwrap();
+            assert_eq!(game.board.len(), len);
+            assert!(game.is_winnable());
+            assert!(game.min_jumps().unwrap() > 0);
+        }
+    }
+
+    #[test_case(5)]
+    #[test_case(20)]
+    #[test_case(100)]
+    fn generates_unwinnable_games(len: usize) {
+        let mut generator = JumpGameGenerator::new(len as u64);
+        for _ in 0..20 {
+            let game = generator.generate(len, Target::Unwinnable).unwrap();
+            assert_eq!(game.board.len(), len);
+            assert!(!game.is_winnable());
+        }
+    }
+
+    #[test_case(10, 3)]
+    #[test_case(20, 5)]
+    #[test_case(50, 8)]
+    fn generates_games_needing_at_least_min_jumps(len: usize, min_jumps: usize) {
+        let mut generator = JumpGameGenerator::new(1).with_max_attempts(10_000);
+        for _ in 0..10 {
+            let game = generator
+                .generate(len, Target::MinJumps(min_jumps))
+                .unwrap();
+            assert!(game.is_winnable());
+            assert!(game.min_jumps().unwrap() >= min_jumps);
+        }
+    }
+
+    #[test]
+    fn respects_the_max_value() {
+        let mut generator = JumpGameGenerator::new(3).with_max_value(2);
+        let game = generator.generate(50, Target::Winnable).unwrap();
+        assert!(game.board.iter().all(|&value| value <= 2));
+    }
+
+    #[test]
+    fn is_deterministic_for_a_seed() {
+        let boards = |seed| {
+            let mut generator = JumpGameGenerator::new(seed);
+            (0..5)
+                .map(|_| {
+                    let game = generator.generate(12, Target::Winnable).unwrap();
+                    (game.board, game.starting_index)
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(boards(99), boards(99));
+        assert_ne!(boards(99), boards(100));
+    }
+
+    #[test_case(vec![1, 0], 0, Target::Winnable => true)]
+    #[test_case(vec![1, 0], 1, Target::Winnable => false ; "starting on the zero")]
+    #[test_case(vec![2, 0, 0], 1, Target::Winnable => false ; "starting on another zero")]
+    #[test_case(vec![2, 2, 0], 1, Target::Unwinnable => true)]
+    #[test_case(vec![2, 2, 0], 0, Target::Unwinnable => false)]
+    #[test_case(vec![1, 1, 1, 0], 0, Target::MinJumps(3) => true)]
+    #[test_case(vec![1, 1, 1, 0], 1, Target::MinJumps(3) => false)]
+    #[test_case(vec![2, 2, 0], 1, Target::MinJumps(1) => false ; "unwinnable")]
+    fn checks_games_against_the_target(
+        board: Vec<usize>,
+        starting_index: usize,
+        target: Target,
+    ) -> bool {
+        target.is_met_by(&JumpGame::new(board, starting_index))
+    }
+
+    #[test_case(0, Target::Winnable)]
+    #[test_case(1, Target::Winnable)]
+    #[test_case(1, Target::Unwinnable)]
+    #[test_case(4, Target::MinJumps(10))]
+    fn gives_up_when_no_game_can_exist(len: usize, target: Target) {
+        let mut generator = JumpGameGenerator::new(0).with_max_attempts(50);
+        assert!(generator.generate(len, target).is_none());
+    }
+}
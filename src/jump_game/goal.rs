@@ -0,0 +1,142 @@
+use std::collections::BTreeSet;
+use std::fmt;
+use std::sync::Arc;
+
+use super::{BoardValue, JumpGameError};
+
+/// # What the player has to reach to win a [`JumpGame`](super::JumpGame).
+///
+/// The game ends as soon as a goal is reached, so there are no jumps out of a goal cell.
+#[derive(Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Goal {
+    /// Land on any cell holding a 0.
+    #[default]
+    ZeroCell,
+    /// Land on any of the given indices.
+    Index(BTreeSet<usize>),
+    /// Land on any cell holding the given value.
+    Value(usize),
+    /// Jump off the right hand end of the board. Never reachable with
+    /// [`Boundary::Wrap`](super::Boundary::Wrap).
+    ExitRight,
+    /// Land on any cell for which the predicate, called with the index and value of the cell,
+    /// returns true. Custom goals can not be serialized.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(Arc<dyn Fn(usize, usize) -> bool + Send + Sync>),
+}
+
+impl Goal {
+    /// # Creates a goal from a predicate over the index and value of each cell.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Goal, JumpGame};
+    /// let goal = Goal::custom(|index, value| index > 3 && value % 2 == 0);
+    /// let game = JumpGame::with_goal(vec![4, 1, 3, 1, 2, 1, 6], 0, goal);
+    /// assert_eq!(game.shortest_path(), Some(vec![0, 4]));
+    /// ```
+    pub fn custom(predicate: impl Fn(usize, usize) -> bool + Send + Sync + 'static) -> Self {
+        Goal::Custom(Arc::new(predicate))
+    }
+
+    /// Checks whether landing on `index`, which holds `value`, wins the game.
+    pub(crate) fn is_goal_cell(&self, index: usize, value: usize) -> bool {
+        match self {
+            Goal::ZeroCell => value == 0,
+            Goal::Index(indices) => indices.contains(&index),
+            Goal::Value(goal_value) => value == *goal_value,
+            Goal::ExitRight => false,
+            Goal::Custom(predicate) => predicate(index, value),
+        }
+    }
+
+    /// Checks whether a jump to `target`, which is off a board of length `len`, wins the game.
+    pub(crate) fn is_winning_exit(&self, target: i128, len: usize) -> bool {
+        matches!(self, Goal::ExitRight) && target >= len as i128
+    }
+
+    /// Makes sure the goal can exist on the given board.
+    pub(crate) fn validate<T: BoardValue>(&self, board: &[T]) -> Result<(), JumpGameError> {
+        let board_len = board.len();
+        let mut values = board.iter().map(|value| value.to_usize());
+        match self {
+            Goal::ZeroCell if !values.any(|value| value == 0) => {
+                Err(JumpGameError::MissingZero { board_len })
+            }
+            Goal::Index(indices) => match indices.iter().find(|&&index| index >= board_len) {
+                Some(&index) => Err(JumpGameError::GoalIndexOutOfBounds { index, board_len }),
+                None if indices.is_empty() => Err(JumpGameError::MissingGoal { board_len }),
+                None => Ok(()),
+            },
+            Goal::Value(goal_value) if !values.any(|value| value == *goal_value) => {
+                Err(JumpGameError::MissingGoalValue {
+                    value: *goal_value,
+                    board_len,
+                })
+            }
+            Goal::Custom(predicate)
+                if !values
+                    .enumerate()
+                    .any(|(index, value)| predicate(index, value)) =>
+            {
+                Err(JumpGameError::MissingGoal { board_len })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl fmt::Debug for Goal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Goal::ZeroCell => write!(f, "ZeroCell"),
+            Goal::Index(indices) => f.debug_tuple("Index").field(indices).finish(),
+            Goal::Value(value) => f.debug_tuple("Value").field(value).finish(),
+            Goal::ExitRight => write!(f, "ExitRight"),
+            Goal::Custom(_) => write!(f, "Custom(..)"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(Goal::ZeroCell, 3, 0, true)]
+    #[test_case(Goal::ZeroCell, 3, 1, false)]
+    #[test_case(Goal::Index(BTreeSet::from([1, 4])), 4, 7, true)]
+    #[test_case(Goal::Index(BTreeSet::from([1, 4])), 3, 0, false)]
+    #[test_case(Goal::Value(7), 2, 7, true)]
+    #[test_case(Goal::Value(7), 2, 0, false)]
+    #[test_case(Goal::ExitRight, 2, 0, false)]
+    #[test_case(Goal::custom(|index, value| index == value), 2, 2, true)]
+    #[test_case(Goal::custom(|index, value| index == value), 2, 3, false)]
+    fn is_goal_cell_cases(goal: Goal, index: usize, value: usize, expected: bool) {
+        assert_eq!(goal.is_goal_cell(index, value), expected);
+    }
+
+    #[test_case(Goal::ExitRight, 5, true)]
+    #[test_case(Goal::ExitRight, 9, true)]
+    #[test_case(Goal::ExitRight, -1, false)]
+    #[test_case(Goal::ZeroCell, 5, false)]
+    fn is_winning_exit_cases(goal: Goal, target: i128, expected: bool) {
+        assert_eq!(goal.is_winning_exit(target, 5), expected);
+    }
+
+    #[test_case(Goal::ZeroCell, Err(JumpGameError::MissingZero { board_len: 3 }))]
+    #[test_case(Goal::Index(BTreeSet::from([0, 2])), Ok(()))]
+    #[test_case(Goal::Index(BTreeSet::new()), Err(JumpGameError::MissingGoal { board_len: 3 }))]
+    #[test_case(Goal::Index(BTreeSet::from([1, 3])), Err(JumpGameError::GoalIndexOutOfBounds { index: 3, board_len: 3 }))]
+    #[test_case(Goal::Value(2), Ok(()))]
+    #[test_case(Goal::Value(4), Err(JumpGameError::MissingGoalValue { value: 4, board_len: 3 }))]
+    #[test_case(Goal::ExitRight, Ok(()))]
+    #[test_case(Goal::custom(|_, value| value > 2), Ok(()))]
+    #[test_case(Goal::custom(|_, value| value > 3), Err(JumpGameError::MissingGoal { board_len: 3 }))]
+    fn validate_cases(goal: Goal, expected: Result<(), JumpGameError>) {
+        assert_eq!(goal.validate(&[1usize, 2, 3]), expected);
+        assert_eq!(goal.validate(&[1u8, 2, 3]), expected);
+    }
+}
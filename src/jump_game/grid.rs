@@ -0,0 +1,405 @@
+use std::error::Error;
+use std::fmt;
+
+use super::{BoardAnalysis, Jump, JumpGame, JumpGameError, JumpRule};
+
+/// A `(row, column)` position on a [`GridJumpGame`].
+pub type Position = (usize, usize);
+
+/// # Which directions a [`GridJumpGame`] cell may jump in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Directions {
+    /// Up, down, left and right.
+    #[default]
+    Four,
+    /// Up, down, left, right and the four diagonals.
+    Eight,
+}
+
+impl Directions {
+    fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Directions::Four => &[(-1, 0), (1, 0), (0, -1), (0, 1)],
+            Directions::Eight => &[
+                (-1, 0),
+                (1, 0),
+                (0, -1),
+                (0, 1),
+                (-1, -1),
+                (-1, 1),
+                (1, -1),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+/// # The reasons a GridJumpGame can fail validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridJumpGameError {
+    /// The grid has no rows, or its rows have no cells.
+    EmptyGrid,
+    /// A row has a different number of cells to the first row.
+    RaggedRow {
+        row: usize,
+        len: usize,
+        expected: usize,
+    },
+    /// The starting position does not point at a cell on the grid.
+    StartingPositionOutOfBounds {
+        starting_position: Position,
+        rows: usize,
+        cols: usize,
+    },
+    /// The grid has no 0 to jump to.
+    MissingZero { rows: usize, cols: usize },
+}
+
+impl fmt::Display for GridJumpGameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridJumpGameError::EmptyGrid => write!(f, "Grid must have at least one cell"),
+            GridJumpGameError::RaggedRow { row, len, expected } => write!(
+                f,
+                "Row {row} has {len} cells but every row must have {expected}"
+            ),
+            GridJumpGameError::StartingPositionOutOfBounds {
+                starting_position: (row, col),
+                rows,
+                cols,
+            } => write!(
+                f,
+                "Starting position ({row}, {col}) must be within bounds of the grid ({rows}x{cols})"
+            ),
+            GridJumpGameError::MissingZero { rows, cols } => {
+                write!(f, "Grid must contain at least one 0 ({rows}x{cols})")
+            }
+        }
+    }
+}
+
+impl Error for GridJumpGameError {}
+
+/// Jumps exactly `value` cells in each direction of a grid stored row by row.
+#[derive(Debug, Clone, Copy)]
+struct GridRule {
+    rows: usize,
+    cols: usize,
+    directions: Directions,
+}
+
+impl JumpRule for GridRule {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, _len: usize, mut visit: F) {
+        let (row, col) = (index / self.cols, index % self.cols);
+        for &(row_step, col_step) in self.directions.offsets() {
+            let next_row = step(row, row_step, value, self.rows);
+            let next_col = step(col, col_step, value, self.cols);
+            // jumps off the grid are dead ends, so there is nothing to visit
+            if let (Some(next_row), Some(next_col)) = (next_row, next_col) {
+                visit(Jump::to(next_row * self.cols + next_col));
+            }
+        }
+    }
+}
+
+/// Moves `value` cells from `position` in the direction of `sign`, or `None` if that leaves the
+/// `0..limit` range.
+fn step(position: usize, sign: isize, value: usize, limit: usize) -> Option<usize> {
+    let next = match sign {
+        -1 => position.checked_sub(value)?,
+        1 => position.checked_add(value)?,
+        _ => position,
+    };
+    (next < limit).then_some(next)
+}
+
+/// # A JumpGame played on a grid instead of a strip.
+///
+/// Each cell holds a jump length, and the player may jump exactly that many cells in any of the
+/// allowed [`Directions`]. Reaching a 0 wins.
+#[derive(Debug, Clone)]
+pub struct GridJumpGame {
+    game: JumpGame<GridRule>,
+}
+
+impl GridJumpGame {
+    /// # Creates a new GridJumpGame with the given grid and starting position.
+    ///
+    /// Panics if the grid is invalid, see [`GridJumpGame::try_new`] for the fallible version.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::GridJumpGame;
+    /// let grid = vec![
+    ///     vec![1, 2, 1],
+    ///     vec![2, 0, 2],
+    ///     vec![1, 2, 1],
+    /// ];
+    /// GridJumpGame::new(grid, (0, 0));
+    /// ```
+    /// ```should_panic
+    /// # use rust_algorithms::jump_game::GridJumpGame;
+    /// // Every row must be the same length
+    /// GridJumpGame::new(vec![vec![1, 0], vec![1]], (0, 0));
+    /// ```
+    pub fn new(grid: Vec<Vec<usize>>, starting_position: Position) -> Self {
+        Self::try_new(grid, starting_position).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new GridJumpGame, returning an error instead of panicking when the grid is
+    /// invalid.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use rust_algorithms::jump_game::{GridJumpGame, GridJumpGameError};
+    /// assert!(GridJumpGame::try_new(vec![vec![1, 0]], (0, 1)).is_ok());
+    /// assert_eq!(GridJumpGame::try_new(vec![], (0, 0)).err(), Some(GridJumpGameError::EmptyGrid));
+    /// assert_eq!(
+    ///     GridJumpGame::try_new(vec![vec![1, 0]], (1, 0)).err(),
+    ///     Some(GridJumpGameError::StartingPositionOutOfBounds {
+    ///         starting_position: (1, 0),
+    ///         rows: 1,
+    ///         cols: 2
+    ///     })
+    /// );
+    /// ```
+    pub fn try_new(
+        grid: Vec<Vec<usize>>,
+        starting_position: Position,
+    ) -> Result<Self, GridJumpGameError> {
+        let rows = grid.len();
+        let cols = grid.first().map_or(0, Vec::len);
+        if cols == 0 {
+            return Err(GridJumpGameError::EmptyGrid);
+        }
+        if let Some((row, cells)) = grid.iter().enumerate().find(|(_, row)| row.len() != cols) {
+            return Err(GridJumpGameError::RaggedRow {
+                row,
+                len: cells.len(),
+                expected: cols,
+            });
+        }
+        let (start_row, start_col) = starting_position;
+        if start_row >= rows || start_col >= cols {
+            return Err(GridJumpGameError::StartingPositionOutOfBounds {
+                starting_position,
+                rows,
+                cols,
+            });
+        }
+
+        let board = grid.into_iter().flatten().collect();
+        let game =
+            JumpGame::try_new(board, start_row * cols + start_col).map_err(
+                |error| match error {
+                    JumpGameError::MissingZero { .. } => {
+                        GridJumpGameError::MissingZero { rows, cols }
+                    }
+                    error => unreachable!("grid was already validated: {error}"),
+                },
+            )?;
+        let rule = GridRule {
+            rows,
+            cols,
+            directions: Directions::Four,
+        };
+        Ok(Self {
+            game: game.with_rule(rule),
+        })
+    }
+
+    /// # Changes which directions each cell may jump in.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Directions, GridJumpGame};
+    /// let grid = vec![
+    ///     vec![1, 3],
+    ///     vec![3, 0],
+    /// ];
+    /// let game = GridJumpGame::new(grid, (0, 0));
+    /// assert!(!game.is_winnable());
+    /// assert!(game.with_directions(Directions::Eight).is_winnable());
+    /// ```
+    pub fn with_directions(self, directions: Directions) -> Self {
+        let rule = GridRule {
+            directions,
+            ..self.game.rule
+        };
+        Self {
+            game: self.game.with_rule(rule),
+        }
+    }
+
+    /// # Checks to see if the GridJumpGame is winnable.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::GridJumpGame;
+    /// let grid = vec![
+    ///     vec![2, 5, 1],
+    ///     vec![5, 5, 1],
+    ///     vec![1, 1, 0],
+    /// ];
+    /// assert!(GridJumpGame::new(grid.clone(), (0, 0)).is_winnable());
+    /// assert!(!GridJumpGame::new(grid, (1, 1)).is_winnable());
+    /// ```
+    pub fn is_winnable(&self) -> bool {
+        self.game.is_winnable()
+    }
+
+    /// # Finds a winning path through the GridJumpGame, if one exists.
+    ///
+    /// It is not necessarily the shortest path, see [`GridJumpGame::shortest_path`].
+    pub fn solve(&self) -> Option<Vec<Position>> {
+        self.game.solve().map(|path| self.positions(path))
+    }
+
+    /// # Finds the fewest number of jumps needed to reach a 0 from the starting position.
+    pub fn min_jumps(&self) -> Option<usize> {
+        self.game.min_jumps()
+    }
+
+    /// # Finds a path with the fewest jumps from the starting position to a 0.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::GridJumpGame;
+    /// let grid = vec![
+    ///     vec![2, 5, 1],
+    ///     vec![5, 5, 1],
+    ///     vec![1, 1, 0],
+    /// ];
+    /// let game = GridJumpGame::new(grid, (0, 0));
+    /// assert_eq!(game.shortest_path(), Some(vec![(0, 0), (2, 0), (2, 1), (2, 2)]));
+    /// ```
+    pub fn shortest_path(&self) -> Option<Vec<Position>> {
+        self.game.shortest_path().map(|path| self.positions(path))
+    }
+
+    /// # Works out the distance to the nearest 0 from every position on the grid.
+    ///
+    /// The starting position of the game is ignored.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::GridJumpGame;
+    /// let grid = vec![
+    ///     vec![1, 1],
+    ///     vec![2, 0],
+    /// ];
+    /// let game = GridJumpGame::new(grid, (0, 0));
+    /// assert_eq!(
+    ///     game.distances(),
+    ///     vec![vec![Some(2), Some(1)], vec![None, Some(0)]]
+    /// );
+    /// ```
+    pub fn distances(&self) -> Vec<Vec<Option<usize>>> {
+        self.rows(self.analyze().distances().to_vec())
+    }
+
+    /// # Checks every starting position of the grid for winnability in a single pass.
+    pub fn winnable_starts(&self) -> Vec<Vec<bool>> {
+        self.rows(self.analyze().winnable_starts())
+    }
+
+    fn analyze(&self) -> BoardAnalysis {
+        self.game.analyze()
+    }
+
+    fn positions(&self, path: Vec<usize>) -> Vec<Position> {
+        let cols = self.game.rule.cols;
+        path.into_iter()
+            .map(|index| (index / cols, index % cols))
+            .collect()
+    }
+
+    fn rows<T: Clone>(&self, cells: Vec<T>) -> Vec<Vec<T>> {
+        cells
+            .chunks(self.game.rule.cols)
+            .map(<[T]>::to_vec)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn grid() -> Vec<Vec<usize>> {
+        vec![vec![2, 5, 1], vec![5, 5, 1], vec![1, 1, 0]]
+    }
+
+    #[test_case((0, 0), Some(3))]
+    #[test_case((0, 2), Some(2))]
+    #[test_case((2, 0), Some(2))]
+    #[test_case((2, 2), Some(0))]
+    #[test_case((1, 1), None)]
+    #[test_case((0, 1), None)]
+    fn min_jumps_cases(starting_position: Position, expected: Option<usize>) {
+        let game = GridJumpGame::new(grid(), starting_position);
+        assert_eq!(game.is_winnable(), expected.is_some());
+        assert_eq!(game.min_jumps(), expected);
+    }
+
+    #[test_case(Directions::Four)]
+    #[test_case(Directions::Eight)]
+    fn solutions_are_valid_paths(directions: Directions) {
+        let grid = vec![vec![1, 2, 1, 3], vec![2, 1, 3, 1], vec![1, 1, 0, 2]];
+        let winnable = GridJumpGame::new(grid.clone(), (0, 0))
+            .with_directions(directions)
+            .winnable_starts();
+        for (row, winnable_row) in winnable.iter().enumerate() {
+            for (col, &winnable) in winnable_row.iter().enumerate() {
+                let game = GridJumpGame::new(grid.clone(), (row, col)).with_directions(directions);
+                assert_eq!(game.is_winnable(), winnable);
+                for path in [game.solve(), game.shortest_path()].into_iter().flatten() {
+                    assert_eq!(path.first(), Some(&(row, col)));
+                    assert_eq!(path.last(), Some(&(2, 2)));
+                    for hop in path.windows(2) {
+                        let value = grid[hop[0].0][hop[0].1];
+                        let row_distance = hop[0].0.abs_diff(hop[1].0);
+                        let col_distance = hop[0].1.abs_diff(hop[1].1);
+                        assert!(row_distance == value || row_distance == 0);
+                        assert!(col_distance == value || col_distance == 0);
+                        if directions == Directions::Four {
+                            assert!(row_distance == 0 || col_distance == 0);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test_case(vec![vec![0, isize::MAX as usize]], (0, 1))]
+    #[test_case(vec![vec![0], vec![usize::MAX]], (1, 0))]
+    fn huge_jumps_leave_the_grid(grid: Vec<Vec<usize>>, starting_position: Position) {
+        let game = GridJumpGame::new(grid, starting_position);
+        assert!(!game.is_winnable());
+        assert!(!game.with_directions(Directions::Eight).is_winnable());
+    }
+
+    #[test]
+    fn jumps_do_not_wrap_onto_the_next_row() {
+        // on the flattened board index 1 + 2 = 3 would be the 0 on the next row
+        let game = GridJumpGame::new(vec![vec![1, 2], vec![0, 1]], (0, 1));
+        assert!(!game.is_winnable());
+    }
+
+    #[test_case(vec![], (0, 0), GridJumpGameError::EmptyGrid)]
+    #[test_case(vec![vec![]], (0, 0), GridJumpGameError::EmptyGrid)]
+    #[test_case(vec![vec![1, 0], vec![1]], (0, 0), GridJumpGameError::RaggedRow { row: 1, len: 1, expected: 2 })]
+    #[test_case(vec![vec![1, 0]], (0, 2), GridJumpGameError::StartingPositionOutOfBounds { starting_position: (0, 2), rows: 1, cols: 2 })]
+    #[test_case(vec![vec![1, 2], vec![3, 4]], (0, 0), GridJumpGameError::MissingZero { rows: 2, cols: 2 })]
+    fn try_new_rejects_invalid_grids(
+        grid: Vec<Vec<usize>>,
+        starting_position: Position,
+        expected: GridJumpGameError,
+    ) {
+        assert_eq!(
+            GridJumpGame::try_new(grid, starting_position).err(),
+            Some(expected)
+        );
+    }
+}
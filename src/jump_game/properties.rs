@@ -0,0 +1,351 @@
+//! Property based tests comparing every solver against a brute force oracle.
+
+use std::collections::{BTreeSet, VecDeque};
+
+use proptest::prelude::*;
+
+use super::{
+    BidirectionalUpTo, BinaryJumpGame, Boundary, ForwardJumpGame, ForwardUpTo, Goal, JumpGame,
+    ScoringJumpGame, TeleportJumpGame,
+};
+
+/// Moves allowed from `index` holding `value`, written out independently of [`JumpRule`](super::JumpRule).
+type Moves = fn(usize, usize) -> Vec<isize>;
+
+fn symmetric_moves(index: usize, value: usize) -> Vec<isize> {
+    vec![
+        index as isize - value as isize,
+        index as isize + value as isize,
+    ]
+}
+
+fn forward_moves(index: usize, value: usize) -> Vec<isize> {
+    (1..=value)
+        .map(|distance| (index + distance) as isize)
+        .collect()
+}
+
+fn bidirectional_moves(index: usize, value: usize) -> Vec<isize> {
+    (1..=value)
+        .flat_map(|distance| {
+            [
+                index as isize - distance as isize,
+                (index + distance) as isize,
+            ]
+        })
+        .collect()
+}
+
+/// Grows the set of reachable indices until it stops changing, then checks it for a 0.
+fn oracle_is_winnable(board: &[usize], starting_index: usize, moves: Moves, wrap: bool) -> bool {
+    let len = board.len() as isize;
+    let mut reachable = vec![false; board.len()];
+    reachable[starting_index] = true;
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for index in 0..board.len() {
+            if !reachable[index] || board[index] == 0 {
+                continue;
+            }
+            for target in moves(index, board[index]) {
+                let target = if wrap { target.rem_euclid(len) } else { target };
+                if (0..len).contains(&target) && !reachable[target as usize] {
+                    reachable[target as usize] = true;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    reachable
+        .iter()
+        .zip(board)
+        .any(|(&reachable, &value)| reachable && value == 0)
+}
+
+/// Checks that `path` is a legal way to win the game.
+fn assert_valid_path(
+    board: &[usize],
+    starting_index: usize,
+    path: &[usize],
+    moves: Moves,
+    wrap: bool,
+) {
+    let len = board.len() as isize;
+    assert_eq!(path.first(), Some(&starting_index));
+    assert_eq!(board[*path.last().unwrap()], 0);
+    for hop in path.windows(2) {
+        assert_ne!(board[hop[0]], 0, "the game ends on the first 0");
+        let landed = moves(hop[0], board[hop[0]])
+            .into_iter()
+            .map(|target| if wrap { target.rem_euclid(len) } else { target })
+            .any(|target| target == hop[1] as isize);
+        assert!(landed, "{} can not jump to {}", hop[0], hop[1]);
+    }
+}
+
+/// The distinct cells `index` can jump to with the default rule.
+fn oracle_cells(board: &[usize], index: usize) -> BTreeSet<usize> {
+    symmetric_moves(index, board[index])
+        .into_iter()
+        .filter_map(|target| usize::try_from(target).ok())
+        .filter(|&target| target < board.len())
+        .collect()
+}
+
+/// Adds every path from `index` that reaches a 0 within the length of `counts`.
+fn oracle_count_paths(board: &[usize], index: usize, jumps: usize, counts: &mut [u128]) {
+    if board[index] == 0 {
+        counts[jumps] += 1;
+    } else if jumps + 1 < counts.len() {
+        for next in oracle_cells(board, index) {
+            oracle_count_paths(board, next, jumps + 1, counts);
+        }
+    }
+}
+
+/// Counts the paths from `index` that reach a 0 without stepping back on to `on_path`.
+fn oracle_count_simple_paths(board: &[usize], index: usize, on_path: &mut [bool]) -> u128 {
+    if board[index] == 0 {
+        return 1;
+    }
+    on_path[index] = true;
+    let mut count = 0;
+    for next in oracle_cells(board, index) {
+        if !on_path[next] {
+            count += oracle_count_simple_paths(board, next, on_path);
+        }
+    }
+    on_path[index] = false;
+    count
+}
+
+/// A board with at least one 0 and a starting index on it.
+fn game() -> impl Strategy<Value = (Vec<usize>, usize)> {
+    (1usize..24).prop_flat_map(|len| {
+        (prop::collection::vec(0..len + 2, len), 0..len, 0..len).prop_map(
+            |(mut board, zero_index, starting_index)| {
+                board[zero_index] = 0;
+                (board, starting_index)
+            },
+        )
+    })
+}
+
+proptest! {
+    #[test]
+    fn is_winnable_matches_the_oracle((board, starting_index) in game()) {
+        let game = JumpGame::new(board.clone(), starting_index);
+        prop_assert_eq!(
+            game.is_winnable(),
+            oracle_is_winnable(&board, starting_index, symmetric_moves, false)
+        );
+    }
+
+    #[test]
+    fn wrapping_is_winnable_matches_the_oracle((board, starting_index) in game()) {
+        let game = JumpGame::new(board.clone(), starting_index).with_boundary(Boundary::Wrap);
+        prop_assert_eq!(
+            game.is_winnable(),
+            oracle_is_winnable(&board, starting_index, symmetric_moves, true)
+        );
+    }
+
+    #[test]
+    fn other_rules_match_the_oracle((board, starting_index) in game()) {
+        let game = JumpGame::new(board.clone(), starting_index);
+        prop_assert_eq!(
+            game.clone().with_rule(ForwardUpTo).is_winnable(),
+            oracle_is_winnable(&board, starting_index, forward_moves, false)
+        );
+        prop_assert_eq!(
+            game.with_rule(BidirectionalUpTo).is_winnable(),
+            oracle_is_winnable(&board, starting_index, bidirectional_moves, false)
+        );
+    }
+
+    #[test]
+    fn starting_on_a_zero_is_always_winnable((mut board, starting_index) in game()) {
+        board[starting_index] = 0;
+        let game = JumpGame::new(board, starting_index);
+        prop_assert!(game.is_winnable());
+        prop_assert_eq!(game.min_jumps(), Some(0));
+        prop_assert_eq!(game.solve(), Some(vec![starting_index]));
+    }
+
+    #[test]
+    fn every_solver_agrees_on_winnability((board, starting_index) in game(), wrap in any::<bool>()) {
+        let boundary = if wrap { Boundary::Wrap } else { Boundary::Wall };
+        let game = JumpGame::new(board.clone(), starting_index).with_boundary(boundary);
+        let winnable = game.is_winnable();
+        prop_assert_eq!(game.solve().is_some(), winnable);
+        prop_assert_eq!(game.shortest_path().is_some(), winnable);
+        prop_assert_eq!(game.min_jumps().is_some(), winnable);
+        prop_assert_eq!(game.cheapest_path(|_, _, _| 1).is_some(), winnable);
+        prop_assert_eq!(game.analyze().is_winnable(starting_index), winnable);
+    }
+
+    #[test]
+    fn solutions_are_valid_paths((board, starting_index) in game(), wrap in any::<bool>()) {
+        let boundary = if wrap { Boundary::Wrap } else { Boundary::Wall };
+        let game = JumpGame::new(board.clone(), starting_index).with_boundary(boundary);
+        let paths = [
+            game.solve(),
+            game.shortest_path(),
+            game.cheapest_path(|from, to, _| from.abs_diff(to) as u64).map(|cheapest| cheapest.path),
+        ];
+        for path in paths.into_iter().flatten() {
+            assert_valid_path(&board, starting_index, &path, symmetric_moves, wrap);
+        }
+    }
+
+    #[test]
+    fn shortest_paths_are_shortest((board, starting_index) in game()) {
+        let game = JumpGame::new(board, starting_index);
+        if let Some(path) = game.solve() {
+            let min_jumps = game.min_jumps().unwrap();
+            prop_assert!(min_jumps < path.len());
+            prop_assert_eq!(game.shortest_path().unwrap().len(), min_jumps + 1);
+            prop_assert_eq!(game.analyze().distance(starting_index), Some(min_jumps));
+            prop_assert_eq!(game.cheapest_path(|_, _, _| 1).unwrap().cost, min_jumps as u64);
+        }
+    }
+
+    #[test]
+    fn edits_make_games_winnable(
+        (board, starting_index) in game(),
+        allowed in prop::collection::vec(0usize..8, 0..3),
+    ) {
+        let game = JumpGame::new(board.clone(), starting_index);
+        let edits = game.min_edits();
+        prop_assert_eq!(edits.as_ref().map(Vec::len) == Some(0), game.is_winnable());
+
+        for edits in [edits, game.min_edits_using(&allowed)].into_iter().flatten() {
+            let mut board = board.clone();
+            for edit in &edits {
+                prop_assert_eq!(board[edit.index], edit.old);
+                prop_assert!(edit.new != 0);
+                board[edit.index] = edit.new;
+            }
+            prop_assert!(JumpGame::new(board, starting_index).is_winnable());
+        }
+    }
+
+    #[test]
+    fn forward_games_match_forward_up_to(board in prop::collection::vec(0usize..6, 1..24)) {
+        let last = board.len() - 1;
+        let oracle = JumpGame::with_goal(board.clone(), 0, Goal::Index(BTreeSet::from([last])))
+            .with_rule(ForwardUpTo);
+        let game = ForwardJumpGame::new(board.clone());
+        prop_assert_eq!(game.is_winnable(), oracle.is_winnable());
+        prop_assert_eq!(game.min_jumps(), oracle.min_jumps());
+
+        for path in [game.solve(), game.shortest_path()].into_iter().flatten() {
+            prop_assert_eq!(path[0], 0);
+            prop_assert_eq!(path[path.len() - 1], last);
+            for hop in path.windows(2) {
+                prop_assert!(hop[0] < hop[1] && hop[1] - hop[0] <= board[hop[0]]);
+            }
+        }
+        prop_assert_eq!(game.shortest_path().map(|path| path.len() - 1), game.min_jumps());
+    }
+
+    #[test]
+    fn scoring_games_find_the_best_score(
+        board in prop::collection::vec(-20i64..20, 1..24),
+        max_jump in 1usize..6,
+    ) {
+        // every index checks every index it can be jumped to from, in O(n * max_jump)
+        let mut best = vec![board[0]];
+        for index in 1..board.len() {
+            let from = index.saturating_sub(max_jump);
+            best.push(best[from..index].iter().max().unwrap() + board[index]);
+        }
+
+        let game = ScoringJumpGame::new(board.clone(), max_jump);
+        let scored = game.best_path();
+        prop_assert_eq!(scored.score, i128::from(best[board.len() - 1]));
+        prop_assert_eq!(scored.path.iter().map(|&index| i128::from(board[index])).sum::<i128>(), scored.score);
+        prop_assert_eq!(scored.path[0], 0);
+        prop_assert_eq!(scored.path[scored.path.len() - 1], board.len() - 1);
+        for hop in scored.path.windows(2) {
+            prop_assert!(hop[0] < hop[1] && hop[1] - hop[0] <= max_jump);
+        }
+    }
+
+    #[test]
+    fn binary_games_match_the_oracle(
+        rest in prop::collection::vec(any::<bool>(), 0..24),
+        min_jump in 1usize..5,
+        extra in 0usize..4,
+    ) {
+        let max_jump = min_jump + extra;
+        let board: String = std::iter::once('0')
+            .chain(rest.iter().map(|&blocked| if blocked { '1' } else { '0' }))
+            .collect();
+        let open: Vec<bool> = board.chars().map(|cell| cell == '0').collect();
+        let mut reachable = vec![true];
+        for (index, &is_open) in open.iter().enumerate().skip(1) {
+            let from = index.saturating_sub(max_jump);
+            let to = index.saturating_sub(min_jump);
+            let can_reach = index >= min_jump && is_open && reachable[from..=to].contains(&true);
+            reachable.push(can_reach);
+        }
+
+        let game = BinaryJumpGame::new(&board, min_jump, max_jump);
+        prop_assert_eq!(game.is_winnable(), reachable[open.len() - 1]);
+        if let Some(path) = game.solve() {
+            prop_assert_eq!(path[0], 0);
+            prop_assert_eq!(path[path.len() - 1], open.len() - 1);
+            for hop in path.windows(2) {
+                prop_assert!(open[hop[1]]);
+                prop_assert!((min_jump..=max_jump).contains(&(hop[1] - hop[0])));
+            }
+        }
+    }
+
+    #[test]
+    fn teleport_games_find_the_fewest_moves(board in prop::collection::vec(0i64..5, 1..24)) {
+        // a plain breadth first search that checks every index for a teleport each time
+        let last = board.len() - 1;
+        let mut distances = vec![None; board.len()];
+        distances[0] = Some(0);
+        let mut queue = VecDeque::from([0]);
+        while let Some(index) = queue.pop_front() {
+            let distance = distances[index].unwrap();
+            for next in 0..board.len() {
+                let moves = next + 1 == index || next == index + 1 || board[next] == board[index];
+                if moves && distances[next].is_none() {
+                    distances[next] = Some(distance + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let game = TeleportJumpGame::new(board.clone());
+        let path = game.shortest_path();
+        prop_assert_eq!(Some(game.min_jumps()), distances[last]);
+        prop_assert_eq!(path.len() - 1, game.min_jumps());
+        prop_assert_eq!(path[0], 0);
+        prop_assert_eq!(path[path.len() - 1], last);
+        for hop in path.windows(2) {
+            prop_assert!(hop[0].abs_diff(hop[1]) == 1 || board[hop[0]] == board[hop[1]]);
+        }
+    }
+
+    #[test]
+    fn path_counts_match_the_oracle((board, starting_index) in game()) {
+        let game = JumpGame::new(board.clone(), starting_index);
+        let mut counts = vec![0; 7];
+        oracle_count_paths(&board, starting_index, 0, &mut counts);
+        prop_assert_eq!(game.count_paths_by_jumps(6), Ok(counts));
+
+        if let Ok(count) = game.count_simple_paths(10_000) {
+            let mut on_path = vec![false; board.len()];
+            prop_assert_eq!(count, oracle_count_simple_paths(&board, starting_index, &mut on_path));
+            prop_assert_eq!(count > 0, game.is_winnable());
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::VecDeque;
+
+use super::game_ref::Landing;
+use super::{BoardValue, JumpGame, JumpGameRef, JumpRule};
+
+/// # A change to the value of one cell of a board, as found by [`JumpGame::min_edits`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Edit {
+    /// The index of the cell to change.
+    pub index: usize,
+    /// The value the cell has now.
+    pub old: usize,
+    /// The value to change it to.
+    pub new: usize,
+}
+
+impl<R: JumpRule> JumpGame<R> {
+    /// # Finds the fewest cell values to change to make the game winnable.
+    ///
+    /// The edits are listed in the order the player meets them along a winning path, and are
+    /// empty if the game can already be won. Edits never add or remove goal cells, so on a board
+    /// that needs a 0 the answer is never just to put a 0 under the player. Returns `None` if no
+    /// edits can make the game winnable.
+    ///
+    /// Every value from 0 up to the length of the board is tried for every cell, which covers
+    /// every jump that can land on the board or leave it, so this takes O(n²) time for the
+    /// default rule.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Edit, JumpGame};
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert!(!game.is_winnable());
+    ///
+    /// let edits = game.min_edits().unwrap();
+    /// assert_eq!(edits, vec![Edit { index: 0, old: 1, new: 2 }]);
+    ///
+    /// let winnable = JumpGame::new(vec![2, 2, 0, 3, 2], 0);
+    /// assert!(winnable.is_winnable());
+    /// assert_eq!(winnable.min_edits(), Some(vec![]));
+    /// ```
+    pub fn min_edits(&self) -> Option<Vec<Edit>> {
+        self.view().min_edits()
+    }
+
+    /// # Finds the fewest cell values to change to make the game winnable, only ever changing a
+    /// cell to one of the `allowed` values, see [`JumpGame::min_edits`].
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Edit, JumpGame};
+    /// let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0);
+    /// assert_eq!(
+    ///     game.min_edits_using(&[1, 4]),
+    ///     Some(vec![Edit { index: 1, old: 2, new: 1 }])
+    /// );
+    /// assert_eq!(game.min_edits_using(&[5]), None);
+    /// ```
+    pub fn min_edits_using(&self, allowed: &[usize]) -> Option<Vec<Edit>> {
+        self.view().min_edits_using(allowed)
+    }
+}
+
+impl<T: BoardValue, R: JumpRule> JumpGameRef<'_, T, R> {
+    /// # Finds the fewest cell values to change to make the game winnable, see
+    /// [`JumpGame::min_edits`].
+    pub fn min_edits(&self) -> Option<Vec<Edit>> {
+        let values: Vec<usize> = (0..=self.board.len()).collect();
+        self.search_edits(&values)
+    }
+
+    /// # Finds the fewest cell values to change to make the game winnable using only the
+    /// `allowed` values, see [`JumpGame::min_edits_using`].
+    pub fn min_edits_using(&self, allowed: &[usize]) -> Option<Vec<Edit>> {
+        let mut values = allowed.to_vec();
+        values.sort_unstable();
+        values.dedup();
+        self.search_edits(&values)
+    }
+
+    /// A 0-1 breadth first search where jumping with a cell's own value is free and jumping
+    /// with a changed value costs one edit.
+    ///
+    /// A cell only ever needs one value, because a path with the fewest edits never has to
+    /// come back to a cell it has already left.
+    fn search_edits(&self, values: &[usize]) -> Option<Vec<Edit>> {
+        // the extra index at the end of the board stands in for jumping off it to win
+        let exit = self.board.len();
+        let mut edits = vec![usize::MAX; exit + 1];
+        // how each index was first reached: the index jumped from and the value it was changed
+        // to, if it was changed
+        let mut parents = vec![None::<(usize, Option<usize>)>; exit + 1];
+        let mut done = vec![false; exit + 1];
+        let mut queue = VecDeque::from([self.starting_index]);
+        edits[self.starting_index] = 0;
+
+        while let Some(current_index) = queue.pop_front() {
+            if done[current_index] {
+                continue;
+            }
+            done[current_index] = true;
+            if current_index == exit || self.is_goal_cell(current_index) {
+                return Some(self.collect_edits(&parents, current_index));
+            }
+
+            let old = self.jump_length(current_index);
+            let moves = std::iter::once((old, 0)).chain(
+                values
+                    .iter()
+                    .filter(|&&new| new != old && !self.goal.is_goal_cell(current_index, new))
+                    .map(|&new| (new, 1)),
+            );
+            for (value, cost) in moves {
+                let next_edits = edits[current_index] + cost;
+                self.rule.targets(current_index, value, exit, |jump| {
+                    let next_index = match self.land(jump) {
+                        Landing::Cell(next_index) => next_index,
+                        Landing::Exit => exit,
+                        Landing::Off => return,
+                    };
+                    if next_edits < edits[next_index] {
+                        edits[next_index] = next_edits;
+                        let edit = (cost == 1).then_some(value);
+                        parents[next_index] = Some((current_index, edit));
+                        if cost == 0 {
+                            queue.push_front(next_index);
+                        } else {
+                            queue.push_back(next_index);
+                        }
+                    }
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Walks the parents back from the end to list the edits made along the way.
+    fn collect_edits(&self, parents: &[Option<(usize, Option<usize>)>], end: usize) -> Vec<Edit> {
+        let mut edits = Vec::new();
+        let mut current_index = end;
+        while let Some((parent, edit)) = parents[current_index] {
+            if let Some(new) = edit {
+                edits.push(Edit {
+                    index: parent,
+                    old: self.value(parent),
+                    new,
+                });
+            }
+            current_index = parent;
+        }
+        edits.reverse();
+        edits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{Boundary, ForwardUpTo, Goal};
+    use test_case::test_case;
+
+    /// Applies the edits to a copy of the board.
+    fn edited(board: &[usize], edits: &[Edit]) -> Vec<usize> {
+        let mut board = board.to_vec();
+        for edit in edits {
+            assert_eq!(board[edit.index], edit.old);
+            board[edit.index] = edit.new;
+        }
+        board
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, 0)]
+    #[test_case(vec![1, 2, 0, 3, 2], 0, 1)]
+    #[test_case(vec![9, 9, 9, 9, 0], 0, 1)]
+    #[test_case(vec![1, 1, 1, 1, 1, 1, 1, 1, 0], 8, 0)]
+    fn finds_the_fewest_edits(board: Vec<usize>, start: usize, expected: usize) {
+        let edits = JumpGame::new(board.clone(), start).min_edits().unwrap();
+        assert_eq!(edits.len(), expected);
+        assert!(JumpGame::new(edited(&board, &edits), start).is_winnable());
+    }
+
+    #[test]
+    fn never_adds_a_goal() {
+        // putting a 0 on the starting cell would win straight away, but that's not a repair
+        let game = JumpGame::new(vec![2, 2, 2, 2, 2, 2, 2, 2, 2, 0], 0);
+        assert_eq!(game.min_edits_using(&[0]), None);
+        let edits = game.min_edits_using(&[0, 1]).unwrap();
+        assert_eq!(
+            edits,
+            vec![Edit {
+                index: 8,
+                old: 2,
+                new: 1
+            }]
+        );
+
+        let game = JumpGame::with_goal(vec![1, 3, 1, 2], 0, Goal::Value(2));
+        let edits = game.min_edits().unwrap();
+        assert_eq!(edits.len(), 1);
+        assert_ne!(edits[0].new, 2);
+    }
+
+    #[test]
+    fn respects_the_allowed_values() {
+        let game = JumpGame::new(vec![4, 4, 4, 4, 4, 4, 4, 4, 4, 0], 0);
+        for allowed in [&[1][..], &[3, 6][..], &[9][..]] {
+            let edits = game.min_edits_using(allowed).unwrap();
+            assert!(edits.iter().all(|edit| allowed.contains(&edit.new)));
+            assert!(
+                JumpGame::new(edited(&[4, 4, 4, 4, 4, 4, 4, 4, 4, 0], &edits), 0).is_winnable()
+            );
+        }
+        assert_eq!(game.min_edits_using(&[]), None);
+        assert_eq!(game.min_edits_using(&[0, 4]), None);
+    }
+
+    #[test]
+    fn can_take_more_than_one_edit() {
+        // with only 2s to hand, 0 has to jump to 2 and 2 has to jump to the 0 at 4
+        let game = JumpGame::new(vec![1, 2, 1, 2, 0], 0);
+        assert_eq!(
+            game.min_edits_using(&[2]),
+            Some(vec![
+                Edit {
+                    index: 0,
+                    old: 1,
+                    new: 2
+                },
+                Edit {
+                    index: 2,
+                    old: 1,
+                    new: 2
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn follows_the_rule_boundary_and_goal() {
+        let game = JumpGame::new(vec![3, 1, 2, 5, 0], 0).with_rule(ForwardUpTo);
+        assert_eq!(game.min_edits(), Some(vec![]));
+
+        let game = JumpGame::new(vec![1, 3, 0, 3, 2], 0).with_boundary(Boundary::Wrap);
+        assert_eq!(game.min_edits(), Some(vec![]));
+
+        let game = JumpGame::with_goal(vec![1, 1, 0], 0, Goal::ExitRight);
+        let edits = game.min_edits().unwrap();
+        assert_eq!(edits.len(), 1);
+        let board = edited(&[1, 1, 0], &edits);
+        assert!(JumpGame::with_goal(board, 0, Goal::ExitRight).is_winnable());
+    }
+
+    #[test]
+    fn borrowed_boards_can_be_repaired() {
+        let board: [u16; 5] = [1, 2, 0, 3, 2];
+        let edits = JumpGameRef::new(&board, 0).min_edits();
+        assert_eq!(edits, JumpGame::new(vec![1, 2, 0, 3, 2], 0).min_edits());
+    }
+}
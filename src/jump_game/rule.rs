@@ -0,0 +1,153 @@
+/// # Where a single jump is aimed, as reported by a [`JumpRule`].
+///
+/// A jump is allowed to leave the board in either direction, it is up to the
+/// [`JumpGame`](super::JumpGame) to decide what happens then. The target is kept as an `i128`,
+/// which holds any index plus or minus any distance exactly, so a wrapping board always knows
+/// where even the longest jump lands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Jump {
+    target: i128,
+}
+
+impl Jump {
+    /// # Jumps straight to `index`.
+    pub fn to(index: usize) -> Self {
+        Self {
+            target: index as i128,
+        }
+    }
+
+    /// # Jumps `distance` cells left of `index`.
+    pub fn left(index: usize, distance: usize) -> Self {
+        Self {
+            target: index as i128 - distance as i128,
+        }
+    }
+
+    /// # Jumps `distance` cells right of `index`.
+    pub fn right(index: usize, distance: usize) -> Self {
+        Self {
+            target: index as i128 + distance as i128,
+        }
+    }
+
+    /// # The index the jump is aimed at, which may be past either end of the board.
+    pub fn target(self) -> i128 {
+        self.target
+    }
+}
+
+/// # Decides which indices can be reached in a single jump.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::{Jump, JumpGame, JumpRule};
+/// /// Jumps to double or half of the current index.
+/// struct Multiplicative;
+///
+/// impl JumpRule for Multiplicative {
+///     fn targets<F: FnMut(Jump)>(&self, index: usize, _value: usize, _len: usize, mut visit: F) {
+///         visit(Jump::to(index / 2));
+///         visit(Jump::right(index, index));
+///     }
+/// }
+///
+/// let game = JumpGame::new(vec![1, 1, 1, 1, 1, 1, 1, 1, 0], 1).with_rule(Multiplicative);
+/// assert_eq!(game.shortest_path(), Some(vec![1, 2, 4, 8]));
+/// ```
+pub trait JumpRule {
+    /// Calls `visit` once for every jump from `index`, whose cell holds `value`, on a board of
+    /// length `len`.
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, len: usize, visit: F);
+}
+
+impl<R: JumpRule + ?Sized> JumpRule for &R {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, len: usize, visit: F) {
+        (**self).targets(index, value, len, visit)
+    }
+}
+
+/// # Jump exactly `value` cells to the left or to the right.
+///
+/// This is the classic rule and the default for [`JumpGame`](super::JumpGame).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SymmetricExact;
+
+impl JumpRule for SymmetricExact {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, _len: usize, mut visit: F) {
+        visit(Jump::left(index, value));
+        visit(Jump::right(index, value));
+    }
+}
+
+/// # Jump anywhere from 1 to `value` cells to the right.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ForwardUpTo;
+
+impl JumpRule for ForwardUpTo {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, len: usize, mut visit: F) {
+        // a jump of len cells is already off the board, or back where it started when wrapping,
+        // so longer jumps can't reach anywhere new
+        for distance in 1..=value.min(len) {
+            visit(Jump::right(index, distance));
+        }
+    }
+}
+
+/// # Jump anywhere from 1 to `value` cells, in either direction.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BidirectionalUpTo;
+
+impl JumpRule for BidirectionalUpTo {
+    fn targets<F: FnMut(Jump)>(&self, index: usize, value: usize, len: usize, mut visit: F) {
+        // as with ForwardUpTo, jumps longer than the board can't reach anywhere new
+        for distance in 1..=value.min(len) {
+            visit(Jump::left(index, distance));
+            visit(Jump::right(index, distance));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    fn collect_targets(rule: impl JumpRule, index: usize, value: usize) -> Vec<i128> {
+        let mut targets = Vec::new();
+        rule.targets(index, value, 10, |jump| targets.push(jump.target()));
+        targets
+    }
+
+    #[test_case(3, 2, vec![1, 5])]
+    #[test_case(1, 3, vec![-2, 4])]
+    #[test_case(2, 0, vec![2, 2])]
+    #[test_case(1, usize::MAX, vec![1 - usize::MAX as i128, 1 + usize::MAX as i128])]
+    fn symmetric_exact_targets(index: usize, value: usize, expected: Vec<i128>) {
+        assert_eq!(collect_targets(SymmetricExact, index, value), expected);
+    }
+
+    #[test_case(3, 2, vec![4, 5])]
+    #[test_case(1, 0, vec![])]
+    #[test_case(1, 400_000_000, vec![2, 3, 4, 5, 6, 7, 8, 9, 10, 11])]
+    fn forward_up_to_targets(index: usize, value: usize, expected: Vec<i128>) {
+        assert_eq!(collect_targets(ForwardUpTo, index, value), expected);
+    }
+
+    #[test_case(3, 2, vec![2, 4, 1, 5])]
+    #[test_case(0, 1, vec![-1, 1])]
+    #[test_case(1, 0, vec![])]
+    #[test_case(8, usize::MAX, vec![7, 9, 6, 10, 5, 11, 4, 12, 3, 13, 2, 14, 1, 15, 0, 16, -1, 17, -2, 18])]
+    fn bidirectional_up_to_targets(index: usize, value: usize, expected: Vec<i128>) {
+        assert_eq!(collect_targets(BidirectionalUpTo, index, value), expected);
+    }
+
+    #[test_case(Jump::to(4), 4)]
+    #[test_case(Jump::left(3, 5), -2)]
+    #[test_case(Jump::right(3, 5), 8)]
+    #[test_case(Jump::left(0, usize::MAX), -(usize::MAX as i128))]
+    #[test_case(Jump::right(usize::MAX, usize::MAX), 2 * usize::MAX as i128)]
+    fn jump_cases(jump: Jump, expected: i128) {
+        assert_eq!(jump.target(), expected);
+    }
+}
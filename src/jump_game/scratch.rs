@@ -0,0 +1,59 @@
+/// # Reusable buffers for [`JumpGame::is_winnable_with`](super::JumpGame::is_winnable_with) and
+/// [`TeleportJumpGame::shortest_path_with`](super::TeleportJumpGame::shortest_path_with).
+///
+/// Keeping one of these around while checking many boards means the buffers only grow to fit
+/// the largest board, instead of being allocated again for every check.
+#[derive(Debug, Clone, Default)]
+pub struct Scratch {
+    visited: Vec<u64>,
+    pub(crate) stack: Vec<usize>,
+}
+
+impl Scratch {
+    /// # Creates empty buffers, they grow as they are used.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Clears the buffers, making sure there is room to track a board of length `len`.
+    pub(crate) fn reset(&mut self, len: usize) {
+        self.visited.clear();
+        self.visited.resize(len.div_ceil(64), 0);
+        self.stack.clear();
+    }
+
+    /// Marks `index` as visited, returning true if it had not been visited before.
+    pub(crate) fn visit(&mut self, index: usize) -> bool {
+        let (word, bit) = (index / 64, 1 << (index % 64));
+        let unvisited = self.visited[word] & bit == 0;
+        self.visited[word] |= bit;
+        unvisited
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visit_only_succeeds_once_per_index() {
+        let mut scratch = Scratch::new();
+        scratch.reset(130);
+        for index in [0, 63, 64, 129] {
+            assert!(scratch.visit(index));
+            assert!(!scratch.visit(index));
+        }
+        assert!(scratch.visit(1));
+    }
+
+    #[test]
+    fn reset_forgets_visited_indices() {
+        let mut scratch = Scratch::new();
+        scratch.reset(10);
+        scratch.visit(3);
+        scratch.stack.push(3);
+        scratch.reset(10);
+        assert!(scratch.visit(3));
+        assert!(scratch.stack.is_empty());
+    }
+}
@@ -0,0 +1,181 @@
+//! Serde support for [`JumpGame`], enabled by the `serde` feature.
+//!
+//! Games are written as their board, starting index, and any non default rule, boundary, or
+//! goal. Reading a game back runs the same validation as [`JumpGame::try_with_goal`], so an
+//! invalid payload is an error rather than an unplayable game.
+
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{Boundary, Goal, JumpGame, JumpRule, RuleKind};
+
+/// The shape of a serialized JumpGame.
+#[derive(Serialize, Deserialize)]
+struct JumpGameData {
+    board: Vec<usize>,
+    starting_index: usize,
+    #[serde(default, skip_serializing_if = "is_default")]
+    rule: RuleKind,
+    #[serde(default, skip_serializing_if = "is_default")]
+    boundary: Boundary,
+    #[serde(default, skip_serializing_if = "is_zero_cell")]
+    goal: Goal,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+fn is_zero_cell(goal: &Goal) -> bool {
+    matches!(goal, Goal::ZeroCell)
+}
+
+impl<R> Serialize for JumpGame<R>
+where
+    R: Clone + Into<RuleKind>,
+{
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        JumpGameData {
+            board: self.board.clone(),
+            starting_index: self.starting_index,
+            rule: self.rule.clone().into(),
+            boundary: self.boundary,
+            goal: self.goal.clone(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de, R> Deserialize<'de> for JumpGame<R>
+where
+    R: JumpRule + TryFrom<RuleKind>,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let data = JumpGameData::deserialize(deserializer)?;
+        let rule = R::try_from(data.rule).map_err(|_| {
+            D::Error::custom(format_args!("the {} rule is not supported here", data.rule))
+        })?;
+        let game = JumpGame::try_with_goal(data.board, data.starting_index, data.goal)
+            .map_err(D::Error::custom)?;
+        Ok(game.with_rule(rule).with_boundary(data.boundary))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::jump_game::{CheapestPath, ForwardUpTo, Puzzle};
+    use test_case::test_case;
+
+    #[test]
+    fn default_games_only_write_the_board_and_start() {
+        let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4);
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(json, r#"{"board":[1,2,3,0,3,2],"starting_index":4}"#);
+
+        let game: JumpGame = serde_json::from_str(&json).unwrap();
+        assert_eq!(game.min_jumps(), Some(2));
+    }
+
+    #[test]
+    fn games_round_trip_with_every_option() {
+        let game = JumpGame::with_goal(vec![3, 1, 2, 5], 0, Goal::Index(BTreeSet::from([3])))
+            .with_rule(RuleKind::Forward)
+            .with_boundary(Boundary::Wrap);
+        let json = serde_json::to_string(&game).unwrap();
+        assert_eq!(
+            json,
+            r#"{"board":[3,1,2,5],"starting_index":0,"rule":"forward","boundary":"wrap","goal":{"index":[3]}}"#
+        );
+
+        let parsed: JumpGame<RuleKind> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_string(), game.to_string());
+        assert_eq!(parsed.rule, RuleKind::Forward);
+    }
+
+    #[test]
+    fn built_in_rules_serialize_by_name() {
+        let game = JumpGame::new(vec![1, 0], 0).with_rule(ForwardUpTo);
+        let json = serde_json::to_string(&game).unwrap();
+        assert!(json.contains(r#""rule":"forward""#));
+        assert!(serde_json::from_str::<JumpGame<ForwardUpTo>>(&json).is_ok());
+        let error = serde_json::from_str::<JumpGame>(&json).unwrap_err();
+        assert!(error
+            .to_string()
+            .contains("the forward rule is not supported here"));
+    }
+
+    #[test_case(
+        r#"{"board":[],"starting_index":0}"#,
+        "Board must have at least one element"
+    )]
+    #[test_case(
+        r#"{"board":[1,0],"starting_index":2}"#,
+        "Starting index 2 must be within bounds"
+    )]
+    #[test_case(
+        r#"{"board":[1,2],"starting_index":0}"#,
+        "Board must contain at least one 0"
+    )]
+    #[test_case(
+        r#"{"board":[1,2],"starting_index":0,"goal":{"value":3}}"#,
+        "Board must contain at least one 3"
+    )]
+    #[test_case(
+        r#"{"board":[1,0],"starting_index":0,"boundary":"soft"}"#,
+        "unknown variant `soft`"
+    )]
+    #[test_case(r#"{"board":[1,0]}"#, "missing field `starting_index`")]
+    fn invalid_games_are_rejected(json: &str, message: &str) {
+        let error = serde_json::from_str::<JumpGame>(json).unwrap_err();
+        assert!(error.to_string().contains(message), "{error}");
+    }
+
+    #[test]
+    fn exit_goals_do_not_need_a_zero() {
+        let json = r#"{"board":[1,2],"starting_index":0,"goal":"exit_right"}"#;
+        let game: JumpGame = serde_json::from_str(json).unwrap();
+        assert!(game.is_winnable());
+    }
+
+    #[test]
+    fn custom_goals_can_not_be_serialized() {
+        let game = JumpGame::with_goal(vec![1, 2], 0, Goal::custom(|_, value| value == 2));
+        assert!(serde_json::to_string(&game).is_err());
+    }
+
+    #[test]
+    fn solutions_round_trip() {
+        let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 0);
+        let cheapest = game.cheapest_path(|_, _, _| 1).unwrap();
+        let json = serde_json::to_string(&cheapest).unwrap();
+        assert_eq!(json, r#"{"cost":2,"path":[0,1,3]}"#);
+        assert_eq!(
+            serde_json::from_str::<CheapestPath>(&json).unwrap(),
+            cheapest
+        );
+
+        let analysis = game.analyze();
+        let json = serde_json::to_string(&analysis).unwrap();
+        assert_eq!(
+            serde_json::from_str::<crate::jump_game::BoardAnalysis>(&json).unwrap(),
+            analysis
+        );
+    }
+
+    #[test]
+    fn puzzles_round_trip() {
+        let puzzle: Puzzle = "name: Example\nboard: 1 0\nstart: 0\nexpect: 1"
+            .parse()
+            .unwrap();
+        let json = serde_json::to_string(&puzzle).unwrap();
+        assert_eq!(
+            json,
+            r#"{"name":"Example","expected":{"min_jumps":1},"game":{"board":[1,0],"starting_index":0}}"#
+        );
+        let parsed: Puzzle = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_string(), puzzle.to_string());
+    }
+}
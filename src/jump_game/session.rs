@@ -0,0 +1,456 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+use super::game_ref::Landing;
+use super::{BoardAnalysis, Jump, JumpGame};
+
+/// # Which way to jump from the current cell.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Direction::Left => write!(f, "left"),
+            Direction::Right => write!(f, "right"),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = MoveError;
+
+    /// Parses `left` or `right`, or just their first letter, in any case.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_ascii_lowercase().as_str() {
+            "l" | "left" => Ok(Direction::Left),
+            "r" | "right" => Ok(Direction::Right),
+            _ => Err(MoveError::InvalidDirection(input.to_string())),
+        }
+    }
+}
+
+/// # How a [`JumpGameSession`] is going.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SessionStatus {
+    /// The player can still win from here.
+    InProgress,
+    /// The player reached a goal.
+    Won,
+    /// No sequence of jumps from here reaches a goal, so the player has to undo to win.
+    Stuck,
+}
+
+/// # The reasons a jump can be refused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// A direction that could not be parsed.
+    InvalidDirection(String),
+    /// The jump would leave the board without winning.
+    OutOfBounds { position: usize, target: i128 },
+    /// The game has already been won.
+    GameOver,
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::InvalidDirection(direction) => {
+                write!(
+                    f,
+                    "`{direction}` is not a direction, expected left or right"
+                )
+            }
+            MoveError::OutOfBounds { position, target } => {
+                write!(
+                    f,
+                    "Jumping from {position} to {target} would leave the board"
+                )
+            }
+            MoveError::GameOver => write!(f, "The game has already been won"),
+        }
+    }
+}
+
+impl Error for MoveError {}
+
+/// # A move in a log that could not be replayed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayError {
+    /// The 1-based position of the move in the log.
+    pub step: usize,
+    pub error: MoveError,
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "move {}: {}", self.step, self.error)
+    }
+}
+
+impl Error for ReplayError {}
+
+/// # A JumpGame being played one jump at a time.
+///
+/// Each jump goes the full value of the current cell to the left or right, just like
+/// [`SymmetricExact`](super::SymmetricExact), and then follows the game's boundary and goal.
+/// Jumps are checked before they are made, so a session is never left in an unplayable state.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::{Direction, JumpGame, JumpGameSession, MoveError, SessionStatus};
+/// let mut session = JumpGameSession::new(JumpGame::new(vec![1, 2, 3, 0, 3, 2], 0));
+/// assert_eq!(session.hint(), Some(Direction::Right));
+/// assert_eq!(
+///     session.jump(Direction::Left),
+///     Err(MoveError::OutOfBounds { position: 0, target: -1 })
+/// );
+///
+/// assert_eq!(session.jump(Direction::Right), Ok(SessionStatus::InProgress));
+/// assert_eq!(session.jump(Direction::Right), Ok(SessionStatus::Won));
+/// assert_eq!(session.history(), [0, 1, 3]);
+///
+/// session.undo();
+/// assert_eq!(session.position(), 1);
+/// assert_eq!(session.redo(), Some(SessionStatus::Won));
+/// assert_eq!(session.move_log(), "right right");
+/// ```
+#[derive(Debug, Clone)]
+pub struct JumpGameSession {
+    game: JumpGame,
+    /// Every cell the player has stood on, starting with the starting index.
+    history: Vec<usize>,
+    /// Every jump made so far, which is one more than the jumps between cells if the last one
+    /// won by leaving the board.
+    moves: Vec<Direction>,
+    /// Undone jumps, the most recently undone last.
+    undone: Vec<Direction>,
+    /// Which cells can still win, worked out once since the board never changes.
+    analysis: BoardAnalysis,
+}
+
+impl JumpGameSession {
+    /// # Starts playing a game from its starting index.
+    pub fn new(game: JumpGame) -> Self {
+        let history = vec![game.starting_index];
+        let analysis = game.analyze();
+        Self {
+            game,
+            history,
+            moves: Vec::new(),
+            undone: Vec::new(),
+            analysis,
+        }
+    }
+
+    /// # The game being played.
+    pub fn game(&self) -> &JumpGame {
+        &self.game
+    }
+
+    /// # The cell the player is standing on, or jumped off the board from to win.
+    pub fn position(&self) -> usize {
+        self.history[self.history.len() - 1]
+    }
+
+    /// # Every cell the player has stood on, in order.
+    pub fn history(&self) -> &[usize] {
+        &self.history
+    }
+
+    /// # Every jump made so far, in order.
+    pub fn moves(&self) -> &[Direction] {
+        &self.moves
+    }
+
+    /// # The number of jumps made so far.
+    pub fn jumps(&self) -> usize {
+        self.moves.len()
+    }
+
+    /// # Whether the game can still be won, has been won, or can't be won without undoing.
+    pub fn status(&self) -> SessionStatus {
+        if self.exited() || self.game.view().is_goal_cell(self.position()) {
+            SessionStatus::Won
+        } else if self.analysis.is_winnable(self.position()) {
+            SessionStatus::InProgress
+        } else {
+            SessionStatus::Stuck
+        }
+    }
+
+    /// # Jumps from the current cell, returning the new status.
+    ///
+    /// Jumps that would leave the board without winning, and jumps after the game is won, are
+    /// refused and leave the session as it was. A jump clears anything that could be redone.
+    pub fn jump(&mut self, direction: Direction) -> Result<SessionStatus, MoveError> {
+        let status = self.apply(direction)?;
+        self.undone.clear();
+        Ok(status)
+    }
+
+    /// # Takes back the last jump, returning false if there was nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        let exited = self.exited();
+        let Some(direction) = self.moves.pop() else {
+            return false;
+        };
+        if !exited {
+            self.history.pop();
+        }
+        self.undone.push(direction);
+        true
+    }
+
+    /// # Makes the last undone jump again, returning the new status or `None` if there was
+    /// nothing to redo.
+    pub fn redo(&mut self) -> Option<SessionStatus> {
+        let direction = self.undone.pop()?;
+        // the jump was made from this same position before it was undone, so it is still valid
+        Some(self.apply(direction).expect("undone jumps can be redone"))
+    }
+
+    /// # Makes every jump in a move log, such as the one from [`JumpGameSession::move_log`].
+    ///
+    /// The log is a list of directions separated by whitespace or commas, see
+    /// [`Direction::from_str`]. Either every jump is made, or none are and the first one that
+    /// could not be made is returned.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGame, JumpGameSession, MoveError, SessionStatus};
+    /// let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4);
+    /// let mut session = JumpGameSession::new(game);
+    ///
+    /// let error = session.replay("l, right, up").unwrap_err();
+    /// assert_eq!((error.step, error.error), (3, MoveError::InvalidDirection("up".to_string())));
+    /// assert_eq!(session.jumps(), 0);
+    ///
+    /// assert_eq!(session.replay("L R"), Ok(SessionStatus::Won));
+    /// assert_eq!(session.history(), [4, 1, 3]);
+    /// ```
+    pub fn replay(&mut self, log: &str) -> Result<SessionStatus, ReplayError> {
+        let mut replayed = self.clone();
+        let mut status = replayed.status();
+        let steps = log
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|step| !step.is_empty());
+        for (index, step) in steps.enumerate() {
+            status = step
+                .parse()
+                .and_then(|direction| replayed.jump(direction))
+                .map_err(|error| ReplayError {
+                    step: index + 1,
+                    error,
+                })?;
+        }
+        *self = replayed;
+        Ok(status)
+    }
+
+    /// # Writes the jumps made so far as a log that [`JumpGameSession::replay`] can read.
+    pub fn move_log(&self) -> String {
+        self.moves
+            .iter()
+            .map(Direction::to_string)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// # Suggests a jump that is on a shortest path to a goal.
+    ///
+    /// Returns `None` unless the game is in progress.
+    pub fn hint(&self) -> Option<Direction> {
+        if self.exited() || self.game.view().is_goal_cell(self.position()) {
+            return None;
+        }
+        [Direction::Left, Direction::Right]
+            .into_iter()
+            .filter_map(|direction| {
+                let distance = match self.game.view().land(self.jump_for(direction)) {
+                    Landing::Cell(index) => self.analysis.distance(index)?,
+                    Landing::Exit => 0,
+                    Landing::Off => return None,
+                };
+                Some((distance, direction))
+            })
+            .min_by_key(|(distance, _)| *distance)
+            .map(|(_, direction)| direction)
+    }
+
+    /// Makes a jump without touching the redo list.
+    fn apply(&mut self, direction: Direction) -> Result<SessionStatus, MoveError> {
+        if self.status() == SessionStatus::Won {
+            return Err(MoveError::GameOver);
+        }
+        let jump = self.jump_for(direction);
+        match self.game.view().land(jump) {
+            Landing::Cell(index) => self.history.push(index),
+            Landing::Exit => {}
+            Landing::Off => {
+                return Err(MoveError::OutOfBounds {
+                    position: self.position(),
+                    target: jump.target(),
+                })
+            }
+        }
+        self.moves.push(direction);
+        Ok(self.status())
+    }
+
+    /// Whether the last jump won by leaving the board.
+    fn exited(&self) -> bool {
+        self.moves.len() == self.history.len()
+    }
+
+    /// Where a jump in the direction is aimed before the boundary is applied.
+    fn jump_for(&self, direction: Direction) -> Jump {
+        let value = self.game.board[self.position()];
+        match direction {
+            Direction::Left => Jump::left(self.position(), value),
+            Direction::Right => Jump::right(self.position(), value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::jump_game::{Boundary, Goal};
+    use test_case::test_case;
+
+    use Direction::{Left, Right};
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, &[Right, Right], SessionStatus::Won, &[0, 1, 3]; "win")]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 4, &[Left], SessionStatus::InProgress, &[4, 1]; "keep going")]
+    #[test_case(vec![0, 1], 0, &[], SessionStatus::Won, &[0]; "starting on the goal")]
+    #[test_case(vec![2, 0, 2, 1], 0, &[Right], SessionStatus::Stuck, &[0, 2]; "stuck in a loop")]
+    #[test_case(vec![1, 0, 3], 2, &[], SessionStatus::Stuck, &[2]; "stuck with nowhere to go")]
+    fn jumps(
+        board: Vec<usize>,
+        start: usize,
+        jumps: &[Direction],
+        status: SessionStatus,
+        history: &[usize],
+    ) {
+        let mut session = JumpGameSession::new(JumpGame::new(board, start));
+        for &direction in jumps {
+            session.jump(direction).unwrap();
+        }
+        assert_eq!(session.status(), status);
+        assert_eq!(session.history(), history);
+    }
+
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 0, Left, MoveError::OutOfBounds { position: 0, target: -1 })]
+    #[test_case(vec![1, 2, 3, 0, 3, 2], 4, Right, MoveError::OutOfBounds { position: 4, target: 7 })]
+    #[test_case(vec![0, 1], 0, Right, MoveError::GameOver)]
+    fn refused_jumps_change_nothing(
+        board: Vec<usize>,
+        start: usize,
+        direction: Direction,
+        error: MoveError,
+    ) {
+        let mut session = JumpGameSession::new(JumpGame::new(board, start));
+        assert_eq!(session.jump(direction), Err(error));
+        assert_eq!((session.history(), session.jumps()), (&[start][..], 0));
+    }
+
+    #[test]
+    fn undo_and_redo_walk_the_history() {
+        let mut session = JumpGameSession::new(JumpGame::new(vec![1, 1, 1, 0], 1));
+        assert!(!session.undo());
+        assert_eq!(session.redo(), None);
+
+        session.jump(Right).unwrap();
+        session.jump(Right).unwrap();
+        assert_eq!(session.history(), [1, 2, 3]);
+        assert!(session.undo());
+        assert!(session.undo());
+        assert_eq!(session.position(), 1);
+        assert_eq!(session.redo(), Some(SessionStatus::InProgress));
+        assert_eq!(session.position(), 2);
+
+        // a new jump throws away what could have been redone
+        session.undo();
+        session.jump(Left).unwrap();
+        assert_eq!(session.history(), [1, 0]);
+        assert_eq!(session.redo(), None);
+        assert_eq!(session.move_log(), "left");
+    }
+
+    #[test]
+    fn exits_can_be_undone_and_redone() {
+        let game = JumpGame::with_goal(vec![1, 2, 1], 0, Goal::ExitRight);
+        let mut session = JumpGameSession::new(game);
+        session.jump(Right).unwrap();
+        assert_eq!(session.jump(Right), Ok(SessionStatus::Won));
+        assert_eq!((session.jumps(), session.history()), (2, &[0, 1][..]));
+        assert_eq!(session.jump(Left), Err(MoveError::GameOver));
+
+        assert!(session.undo());
+        assert_eq!(
+            (session.jumps(), session.status()),
+            (1, SessionStatus::InProgress)
+        );
+        assert_eq!(session.redo(), Some(SessionStatus::Won));
+        assert_eq!(session.move_log(), "right right");
+    }
+
+    #[test]
+    fn wraps_follow_the_game() {
+        let game = JumpGame::new(vec![1, 2, 0, 3, 2], 0).with_boundary(Boundary::Wrap);
+        let mut session = JumpGameSession::new(game);
+        assert_eq!(session.hint(), Some(Left));
+        session.jump(Left).unwrap();
+        assert_eq!(session.position(), 4);
+    }
+
+    #[test]
+    fn hints_follow_a_shortest_path() {
+        let game = JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4);
+        let min_jumps = game.min_jumps();
+        let mut session = JumpGameSession::new(game);
+        while let Some(direction) = session.hint() {
+            session.jump(direction).unwrap();
+        }
+        assert_eq!(session.status(), SessionStatus::Won);
+        assert_eq!(Some(session.jumps()), min_jumps);
+    }
+
+    #[test]
+    fn no_hints_without_a_way_to_win() {
+        let session = JumpGameSession::new(JumpGame::new(vec![1, 2, 0, 3, 2], 0));
+        assert_eq!(session.status(), SessionStatus::Stuck);
+        assert_eq!(session.hint(), None);
+    }
+
+    #[test_case("", SessionStatus::InProgress, &[4])]
+    #[test_case("left right", SessionStatus::Won, &[4, 1, 3])]
+    #[test_case("L,\tR\n", SessionStatus::Won, &[4, 1, 3])]
+    #[test_case("L\r\nR\r\n", SessionStatus::Won, &[4, 1, 3])]
+    #[test_case("left", SessionStatus::InProgress, &[4, 1])]
+    fn replays_move_logs(log: &str, status: SessionStatus, history: &[usize]) {
+        let mut session = JumpGameSession::new(JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4));
+        assert_eq!(session.replay(log), Ok(status));
+        assert_eq!(session.history(), history);
+
+        let mut again = JumpGameSession::new(session.game().clone());
+        again.replay(&session.move_log()).unwrap();
+        assert_eq!(again.history(), history);
+    }
+
+    #[test_case("left sideways", 2, MoveError::InvalidDirection("sideways".to_string()))]
+    #[test_case("left left", 2, MoveError::OutOfBounds { position: 1, target: -1 })]
+    #[test_case("l r l", 3, MoveError::GameOver)]
+    #[test_case("l\r\nl\r\n", 2, MoveError::OutOfBounds { position: 1, target: -1 })]
+    #[test_case("l\r\nup\r\n", 2, MoveError::InvalidDirection("up".to_string()))]
+    fn bad_move_logs_change_nothing(log: &str, step: usize, error: MoveError) {
+        let mut session = JumpGameSession::new(JumpGame::new(vec![1, 2, 3, 0, 3, 2], 4));
+        assert_eq!(session.replay(log), Err(ReplayError { step, error }));
+        assert_eq!(session.history(), [4]);
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, VecDeque};
+
+use super::game_ref::build_path;
+use super::{validate_board, JumpGameError, Scratch};
+
+/// # A jump game where equal values teleport to each other, better known as Jump Game IV.
+///
+/// The player starts on the first index and can step to either neighbouring index, or teleport
+/// to any other index holding the same value. Reaching the last index wins, which is always
+/// possible by stepping right.
+///
+/// ## Example
+/// ```
+/// # use rust_algorithms::jump_game::TeleportJumpGame;
+/// let game = TeleportJumpGame::new(vec![100, -23, -23, 404, 100, 23, 23, 23, 3, 404]);
+/// assert_eq!(game.min_jumps(), 3);
+/// assert_eq!(game.shortest_path(), vec![0, 4, 3, 9]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TeleportJumpGame {
+    board: Vec<i64>,
+}
+
+impl TeleportJumpGame {
+    /// # Creates a new TeleportJumpGame with the given board.
+    ///
+    /// Panics if the board is empty, see [`TeleportJumpGame::try_new`] for the fallible version.
+    pub fn new(board: Vec<i64>) -> Self {
+        Self::try_new(board).unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// # Creates a new TeleportJumpGame, returning an error instead of panicking when the board
+    /// is empty.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{JumpGameError, TeleportJumpGame};
+    /// assert!(TeleportJumpGame::try_new(vec![7]).is_ok());
+    /// assert_eq!(TeleportJumpGame::try_new(vec![]), Err(JumpGameError::EmptyBoard));
+    /// ```
+    pub fn try_new(board: Vec<i64>) -> Result<Self, JumpGameError> {
+        validate_board(&board)?;
+        Ok(Self { board })
+    }
+
+    /// # The values on the board.
+    pub fn board(&self) -> &[i64] {
+        &self.board
+    }
+
+    /// # Finds the fewest number of moves needed to reach the last index.
+    pub fn min_jumps(&self) -> usize {
+        self.shortest_path().len() - 1
+    }
+
+    /// # Finds a path with the fewest moves to the last index.
+    pub fn shortest_path(&self) -> Vec<usize> {
+        self.shortest_path_with(&mut Scratch::new())
+    }
+
+    /// # Finds a path with the fewest moves to the last index, reusing the buffers in
+    /// `scratch`.
+    ///
+    /// A breadth first search that groups the indices by value. The first time any index of a
+    /// group is reached every other index in it is queued, so the group is removed and no
+    /// index ever looks through it again. Without that, a board full of one value would check
+    /// every pair of indices.
+    ///
+    /// ## Example
+    /// ```
+    /// # use rust_algorithms::jump_game::{Scratch, TeleportJumpGame};
+    /// let mut scratch = Scratch::new();
+    /// for (board, jumps) in [(vec![7], 0), (vec![7, 6, 9, 6, 9, 6, 9, 7], 1), (vec![6, 1, 9], 2)] {
+    ///     let path = TeleportJumpGame::new(board).shortest_path_with(&mut scratch);
+    ///     assert_eq!(path.len() - 1, jumps);
+    /// }
+    /// ```
+    pub fn shortest_path_with(&self, scratch: &mut Scratch) -> Vec<usize> {
+        let last = self.board.len() - 1;
+        let mut groups = HashMap::<i64, Vec<usize>>::new();
+        for (index, &value) in self.board.iter().enumerate() {
+            groups.entry(value).or_default().push(index);
+        }
+
+        let mut queue = VecDeque::<usize>::new();
+        let mut parents = HashMap::<usize, usize>::new();
+        scratch.reset(self.board.len());
+        scratch.visit(0);
+        queue.push_back(0);
+
+        while let Some(current_index) = queue.pop_front() {
+            if current_index == last {
+                break;
+            }
+            let steps = [current_index.checked_sub(1), Some(current_index + 1)];
+            let teleports = groups
+                .remove(&self.board[current_index])
+                .unwrap_or_default();
+            for next_index in steps.into_iter().flatten().chain(teleports) {
+                if next_index <= last && scratch.visit(next_index) {
+                    parents.insert(next_index, current_index);
+                    queue.push_back(next_index);
+                }
+            }
+        }
+
+        build_path(&parents, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use test_case::test_case;
+
+    #[test_case(vec![100, -23, -23, 404, 100, 23, 23, 23, 3, 404], vec![0, 4, 3, 9])]
+    #[test_case(vec![7], vec![0])]
+    #[test_case(vec![7, 6, 9, 6, 9, 6, 9, 7], vec![0, 7])]
+    #[test_case(vec![6, 1, 9], vec![0, 1, 2])]
+    #[test_case(vec![11, 22, 7, 7, 7, 7, 7, 7, 7, 22, 13], vec![0, 1, 9, 10])]
+    #[test_case(vec![1, 2, 3, 4, 1, 9, 9, 9, 9, 9], vec![0, 4, 5, 9])]
+    #[test_case(vec![5, 5, 5, 5, 5], vec![0, 4])]
+    fn test_cases(board: Vec<i64>, expected: Vec<usize>) {
+        let game = TeleportJumpGame::new(board);
+        assert_eq!(game.min_jumps(), expected.len() - 1);
+        assert_eq!(game.shortest_path(), expected);
+    }
+
+    #[test]
+    fn large_boards_of_one_value_are_fast() {
+        let game = TeleportJumpGame::new(vec![0; 100_000]);
+        assert_eq!(game.shortest_path(), vec![0, 99_999]);
+    }
+}